@@ -0,0 +1,254 @@
+use proc_macro2::Span;
+use syn::parse::ParseStream;
+use syn::{Attribute, Expr, Ident, Lit, LitInt, LitStr, Meta, Path, Result, Token};
+
+/// The order of `VARIANTS` for enums whose variants are all unit variants.
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    /// Sorted by discriminant value.
+    #[default]
+    Discriminant,
+    /// The order in which the variants are declared.
+    Declaration,
+}
+
+/// A case convention for `#[enumable(rename_all = "...")]`, named as in serde.
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    const ALL: &[(&str, RenameRule)] = &[
+        ("lowercase", RenameRule::Lower),
+        ("UPPERCASE", RenameRule::Upper),
+        ("PascalCase", RenameRule::Pascal),
+        ("camelCase", RenameRule::Camel),
+        ("snake_case", RenameRule::Snake),
+        ("SCREAMING_SNAKE_CASE", RenameRule::ScreamingSnake),
+        ("kebab-case", RenameRule::Kebab),
+        ("SCREAMING-KEBAB-CASE", RenameRule::ScreamingKebab),
+    ];
+
+    fn parse(value: &LitStr) -> Result<Self> {
+        let name = value.value();
+        match Self::ALL.iter().find(|(rule, _)| *rule == name) {
+            Some((_, rule)) => Ok(*rule),
+            None => {
+                let rules = Self::ALL
+                    .iter()
+                    .map(|(rule, _)| format!("\"{rule}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Err(syn::Error::new_spanned(
+                    value,
+                    format!("unknown rename rule, expected one of {rules}"),
+                ))
+            }
+        }
+    }
+
+    /// Applies the rule to a variant name, which is assumed to be in PascalCase.
+    pub fn apply(self, variant: &str) -> String {
+        match self {
+            RenameRule::Lower => variant.to_ascii_lowercase(),
+            RenameRule::Upper => variant.to_ascii_uppercase(),
+            RenameRule::Pascal => variant.to_owned(),
+            RenameRule::Camel => {
+                let mut chars = variant.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => String::new(),
+                }
+            }
+            RenameRule::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            RenameRule::ScreamingSnake => RenameRule::Snake.apply(variant).to_ascii_uppercase(),
+            RenameRule::Kebab => RenameRule::Snake.apply(variant).replace('_', "-"),
+            RenameRule::ScreamingKebab => {
+                RenameRule::ScreamingSnake.apply(variant).replace('_', "-")
+            }
+        }
+    }
+}
+
+/// Options from `#[enumable(...)]` attributes on the type.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    pub order: Order,
+    /// The `order` option, kept to report it on types it does not apply to.
+    pub order_attr: Option<LitStr>,
+    pub rename_all: Option<RenameRule>,
+    /// Whether `from_name` ignores ASCII case.
+    pub ascii_case_insensitive: bool,
+    /// The first option about variant names, kept to report it on types it does not
+    /// apply to.
+    pub naming_attr: Option<Path>,
+    /// The values of an integer newtype, as in `range = 0..16`.
+    pub range: Option<IntRange>,
+}
+
+/// An inclusive range of integers from `#[enumable(range = ...)]`.
+pub struct IntRange {
+    pub start: i128,
+    pub last: i128,
+    /// The start of the attribute value, kept to report errors.
+    pub span: Span,
+}
+
+impl IntRange {
+    /// Parses `start..end` or `start..=last` from integer literals, which may be negative.
+    fn parse(input: ParseStream) -> Result<Self> {
+        fn bound(input: ParseStream) -> Result<i128> {
+            let negative = input.parse::<Option<Token![-]>>()?.is_some();
+            let value = input.parse::<LitInt>()?.base10_parse::<i128>()?;
+            Ok(if negative { -value } else { value })
+        }
+
+        let span = input.span();
+        let start = bound(input)?;
+        let last = if input.parse::<Option<Token![..=]>>()?.is_some() {
+            bound(input)?
+        } else {
+            input.parse::<Token![..]>()?;
+            bound(input)? - 1
+        };
+        if last < start {
+            return Err(syn::Error::new(span, "the range must not be empty"));
+        }
+        Ok(Self { start, last, span })
+    }
+
+    /// The number of values in the range.
+    pub fn count(&self) -> Result<usize> {
+        self.last
+            .checked_sub(self.start)
+            .and_then(|span| usize::try_from(span).ok())
+            .and_then(|span| span.checked_add(1))
+            .ok_or_else(|| syn::Error::new(self.span, "the range is too large"))
+    }
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut this = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("enumable")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("order") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    this.order = match value.value().as_str() {
+                        "discriminant" => Order::Discriminant,
+                        "declaration" => Order::Declaration,
+                        _ => {
+                            return Err(syn::Error::new_spanned(
+                                &value,
+                                r#"expected `order = "discriminant"` or `order = "declaration"`"#,
+                            ));
+                        }
+                    };
+                    this.order_attr = Some(value);
+                    Ok(())
+                } else if meta.path.is_ident("rename_all") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    this.rename_all = Some(RenameRule::parse(&value)?);
+                    this.naming_attr.get_or_insert(meta.path);
+                    Ok(())
+                } else if meta.path.is_ident("range") {
+                    this.range = Some(IntRange::parse(meta.value()?)?);
+                    Ok(())
+                } else if meta.path.is_ident("ascii_case_insensitive") {
+                    this.ascii_case_insensitive = true;
+                    this.naming_attr.get_or_insert(meta.path);
+                    Ok(())
+                } else {
+                    Err(meta.error("unknown enumable attribute"))
+                }
+            })?;
+        }
+        Ok(this)
+    }
+}
+
+/// Options from `#[enumable(...)]` attributes on a variant.
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub rename: Option<LitStr>,
+    /// Additional names accepted by `from_name`.
+    pub aliases: Vec<LitStr>,
+    /// The groups the variant belongs to.
+    pub groups: Vec<LitStr>,
+    /// The properties of the variant, as in `prop(weight = 3)`.
+    pub props: Vec<(Ident, Expr)>,
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut this = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("enumable")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    this.rename = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("alias") {
+                    this.aliases.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("group") {
+                    this.groups.push(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("prop") {
+                    meta.parse_nested_meta(|prop| {
+                        let Some(key) = prop.path.get_ident().cloned() else {
+                            return Err(prop.error("expected a property name"));
+                        };
+                        if this.props.iter().any(|(other, _)| *other == key) {
+                            return Err(prop.error(format!("duplicate property `{key}`")));
+                        }
+                        this.props.push((key, prop.value()?.parse()?));
+                        Ok(())
+                    })
+                } else {
+                    Err(meta.error("unknown enumable attribute"))
+                }
+            })?;
+        }
+        Ok(this)
+    }
+
+    /// Returns the first `#[enumable(...)]` attribute, to report it on variants it does
+    /// not apply to.
+    pub fn find(attrs: &[Attribute]) -> Option<&Attribute> {
+        attrs.iter().find(|attr| attr.path().is_ident("enumable"))
+    }
+}
+
+/// Collects the `///` doc comment in `attrs`, one line per attribute, without the space
+/// that usually follows `///`.
+pub fn doc_comment(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("doc")) {
+        if let Meta::NameValue(meta) = &attr.meta {
+            if let Expr::Lit(expr) = &meta.value {
+                if let Lit::Str(doc) = &expr.lit {
+                    let doc = doc.value();
+                    lines.push(doc.strip_prefix(' ').unwrap_or(&doc).trim_end().to_owned());
+                }
+            }
+        }
+    }
+    lines.join("\n").trim().to_owned()
+}
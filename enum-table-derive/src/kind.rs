@@ -0,0 +1,75 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, LitStr, Result};
+
+pub fn derive_enum_kind(input: DeriveInput) -> Result<TokenStream> {
+    let Data::Enum(data_enum) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input,
+            "EnumKind can only be derived for enums",
+        ));
+    };
+
+    let mut kind = format_ident!("{}Kind", input.ident);
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("enum_kind"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                kind = name.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("unknown enum_kind attribute"))
+            }
+        })?;
+    }
+
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let variants = data_enum.variants.iter().map(|v| {
+        let docs = v.attrs.iter().filter(|attr| attr.path().is_ident("doc"));
+        let ident = &v.ident;
+        quote! { #(#docs)* #ident }
+    });
+    let arms = data_enum.variants.iter().map(|v| {
+        let ident = &v.ident;
+        let pattern = match &v.fields {
+            Fields::Named(_) => quote! { #ident { .. } },
+            Fields::Unnamed(_) => quote! { #ident(..) },
+            Fields::Unit => quote! { #ident },
+        };
+        quote! { Self::#pattern => #kind::#ident, }
+    });
+    let kind_doc = format!(" The kind of [`{ident}`], with the same variants and no payloads.");
+    let fn_doc = format!(" Returns the [`{kind}`] of this value.");
+
+    Ok(quote! {
+        #[doc = #kind_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, enum_table::Enumable)]
+        #vis enum #kind {
+            #(#variants,)*
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #[doc = #fn_doc]
+            #vis fn kind(&self) -> #kind {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+
+        impl #impl_generics enum_table::EnumKind for #ident #ty_generics #where_clause {
+            type Kind = #kind;
+
+            fn kind(&self) -> #kind {
+                #ident::kind(self)
+            }
+        }
+    })
+}
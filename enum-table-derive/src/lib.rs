@@ -1,52 +1,1020 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::Data;
 use syn::Result;
+use syn::ext::IdentExt;
+use syn::{Data, DataEnum, DataStruct, Fields, Ident, Index, LitStr, Member, Type, Visibility};
 use syn::{DeriveInput, parse_macro_input};
 
-#[proc_macro_derive(Enumable)]
+mod attrs;
+use attrs::{ContainerAttrs, IntRange, Order, VariantAttrs};
+
+mod kind;
+
+#[proc_macro_derive(Enumable, attributes(enumable))]
 pub fn derive_enumable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_enumable_internal(parse_macro_input!(input as DeriveInput))
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
 
+#[proc_macro_derive(EnumKind, attributes(enum_kind))]
+pub fn derive_enum_kind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    kind::derive_enum_kind(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 fn derive_enumable_internal(input: DeriveInput) -> Result<TokenStream> {
-    let Data::Enum(data_enum) = input.data else {
+    let mut output = derive_enumable_impls(&input)?;
+    output.extend(derive_table(&input.ident));
+    Ok(output)
+}
+
+fn derive_enumable_impls(input: &DeriveInput) -> Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&input.attrs)?;
+
+    let is_unit_enum = match &input.data {
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .all(|v| matches!(v.fields, Fields::Unit)),
+        _ => false,
+    };
+    if let Some(order) = &attrs.order_attr {
+        if attrs.order == Order::Discriminant && !is_unit_enum {
+            return Err(syn::Error::new_spanned(
+                order,
+                "only enums with unit variants alone can be ordered by discriminant",
+            ));
+        }
+    }
+    if !is_unit_enum {
+        if let Some(naming) = &attrs.naming_attr {
+            return Err(syn::Error::new_spanned(
+                naming,
+                "only enums with unit variants alone have variant names",
+            ));
+        }
+    }
+
+    if let Some(range) = &attrs.range {
+        return derive_int_newtype(input, range);
+    }
+
+    let data_enum = match &input.data {
+        Data::Enum(data_enum) => data_enum,
+        Data::Struct(data_struct) => {
+            if !input.generics.params.is_empty() {
+                return Err(syn::Error::new_spanned(
+                    &input.generics,
+                    "Enumable cannot be derived for generic structs",
+                ));
+            }
+            return Ok(derive_struct(&input.ident, data_struct));
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "Enumable can only be derived for enums and structs",
+            ));
+        }
+    };
+
+    if is_unit_enum {
+        let int_repr = int_repr(&input.attrs)?;
+        // The generated items share the `SIGNED` constant declared around them.
+        let signed = quote! { SIGNED };
+        let mut output = derive_unit_enum(&input.ident, data_enum, attrs.order, &signed);
+        output.extend(derive_names(&input.ident, data_enum, &attrs, &signed)?);
+        output.extend(derive_groups(
+            &input.ident,
+            &input.vis,
+            data_enum,
+            attrs.order,
+            &signed,
+        )?);
+        output.extend(derive_props(
+            &input.ident,
+            &input.vis,
+            data_enum,
+            attrs.order,
+            &signed,
+        )?);
+        if let Some(repr) = &int_repr {
+            output.extend(derive_repr(&input.ident, repr));
+        }
+        let is_signed = is_signed(&input.ident, int_repr.as_ref(), data_enum);
+        return Ok(quote! {
+            const _: () = {
+                const SIGNED: bool = #is_signed;
+
+                #output
+            };
+        });
+    }
+
+    if !input.generics.params.is_empty() {
         return Err(syn::Error::new_spanned(
-            &input,
-            "Enumable can only be derived for enums",
+            &input.generics,
+            "Enumable cannot be derived for generic enums with data-carrying variants",
         ));
-    };
+    }
 
+    derive_data_enum(&input.ident, data_enum)
+}
+
+/// Implements `EnumableTable`, naming the types sized by the count of the concrete type.
+fn derive_table(ident: &Ident) -> TokenStream {
+    quote! {
+        impl enum_table::EnumableTable for #ident {
+            type Table<__V> = enum_table::EnumTable<#ident, __V, { <#ident as enum_table::Enumable>::COUNT }>;
+            type Bits = [u64; <#ident as enum_table::Enumable>::COUNT.div_ceil(64)];
+            type BitsArray<__B: enum_table::set::Bits> = [__B; <#ident as enum_table::Enumable>::COUNT];
+        }
+    }
+}
+
+/// The primitive integer types, which are also the integer `repr`s.
+const INTS: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Returns the primitive integer `repr` of the enum, if any.
+fn int_repr(attrs: &[syn::Attribute]) -> Result<Option<Ident>> {
+    let mut int_repr = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTS.contains(&ident.to_string().as_str()) {
+                    int_repr = Some(ident.clone());
+                }
+            }
+            // Skip arguments such as `align(2)`.
+            if meta.input.peek(syn::token::Paren) {
+                let _content;
+                syn::parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        })?;
+    }
+    Ok(int_repr)
+}
+
+/// Returns a `const` expression that is `true` if the discriminants of the enum are signed
+/// integers.
+///
+/// That is the case with a signed `repr`, or without an integer `repr` when any
+/// discriminant is negative, as the compiler then picks a signed integer for them. Explicit
+/// discriminants can be any constant expression, so that is checked once they are evaluated.
+fn is_signed(ident: &Ident, int_repr: Option<&Ident>, data_enum: &DataEnum) -> TokenStream {
+    if let Some(repr) = int_repr {
+        let signed = repr.to_string().starts_with('i');
+        return quote! { #signed };
+    }
+    if data_enum.variants.iter().all(|v| v.discriminant.is_none()) {
+        return quote! { false };
+    }
+    let variant_idents = data_enum.variants.iter().map(|v| &v.ident);
+    quote! {
+        enum_table::__private::has_negative_discriminants::<#ident>(&[#(#ident::#variant_idents as u128),*])
+    }
+}
+
+fn derive_unit_enum(
+    ident: &Ident,
+    data_enum: &DataEnum,
+    order: Order,
+    signed: &TokenStream,
+) -> TokenStream {
     let variant_idents = data_enum
+        .variants
+        .iter()
+        .map(|v| &v.ident)
+        .collect::<Vec<_>>();
+    let count = variant_idents.len();
+
+    let (variants, fallback) = match order {
+        Order::Discriminant => (
+            quote! {
+                &if #signed {
+                    enum_table::__private::sort_signed_variants([#(Self::#variant_idents),*])
+                } else {
+                    enum_table::__private::sort_variants([#(Self::#variant_idents),*])
+                }
+            },
+            quote! {
+                if #signed {
+                    enum_table::layout::Layout::signed::<#ident>()
+                } else {
+                    enum_table::layout::Layout::sorted::<#ident>()
+                }
+            },
+        ),
+        Order::Declaration => (
+            quote! { &[#(Self::#variant_idents),*] },
+            quote! {{
+                const ORDER: [usize; #count] = enum_table::__private::sorted_order(<#ident as enum_table::Enumable>::VARIANTS, #signed);
+                enum_table::layout::Layout::permuted::<#ident>(&ORDER, #signed)
+            }},
+        ),
+    };
+
+    let ordinals = 0..count;
+    let ordinal = quote! {
+        match *self {
+            #( Self::#variant_idents => #ordinals, )*
+        }
+    };
+    // The index is the declaration ordinal, or the position of the variant once sorted,
+    // which is computed for all variants at once to keep `const` evaluation O(N log N).
+    let variant_index = match order {
+        Order::Discriminant if count > 0 => quote! {
+            const POSITIONS: [usize; #count] = enum_table::__private::sorted_positions::<#count, #ident>(
+                [#(#ident::#variant_idents as u128),*],
+                #signed,
+            );
+            POSITIONS[#ordinal]
+        },
+        _ => ordinal,
+    };
+
+    // `const` lookups index a table by discriminant, unless the discriminants are too
+    // sparse, in which case `fallback` binary-searches them.
+    let layout = quote! {
+        const LAYOUT: enum_table::layout::Layout = {
+            const SPAN: usize = enum_table::__private::dense_span(<#ident as enum_table::Enumable>::VARIANTS, #signed);
+            const DENSE: [usize; SPAN] = enum_table::__private::dense_indices(<#ident as enum_table::Enumable>::VARIANTS, #signed);
+            enum_table::layout::Layout::dense::<#ident>(&DENSE, #signed, #fallback)
+        };
+    };
+
+    quote! {
+        impl enum_table::Enumable for #ident {
+            const VARIANTS: &'static [#ident] = #variants;
+
+            #layout
+
+            fn variant_index(&self) -> usize {
+                // Discriminants running from 0 in the order of `VARIANTS` are the indices.
+                if const { enum_table::__private::discriminants_are_indices(<#ident as enum_table::Enumable>::VARIANTS) } {
+                    return *self as usize;
+                }
+                #variant_index
+            }
+        }
+    }
+}
+
+/// A variant of a data-carrying enum: either a unit variant or one holding a single
+/// `Enumable` field.
+struct Arm<'a> {
+    ident: &'a Ident,
+    payload: Option<Payload<'a>>,
+}
+
+struct Payload<'a> {
+    /// The field name for struct-like variants, `None` for tuple variants.
+    name: Option<&'a Ident>,
+    ty: &'a Type,
+}
+
+impl Arm<'_> {
+    /// The number of entries this arm contributes to `VARIANTS`.
+    fn count(&self) -> TokenStream {
+        match &self.payload {
+            Some(Payload { ty, .. }) => quote! { <#ty as enum_table::Enumable>::COUNT },
+            None => quote! { 1usize },
+        }
+    }
+
+    /// A pattern matching this arm of `ty` and binding its payload to `value`.
+    ///
+    /// Patterns and constructors share the same syntax, so this also builds the arm
+    /// from a payload named `value`.
+    fn pattern(&self, ty: &TokenStream) -> TokenStream {
+        let ident = self.ident;
+        match &self.payload {
+            Some(Payload {
+                name: Some(name), ..
+            }) => quote! { #ty::#ident { #name: value } },
+            Some(Payload { name: None, .. }) => quote! { #ty::#ident(value) },
+            None => quote! { #ty::#ident },
+        }
+    }
+}
+
+fn parse_arms(data_enum: &DataEnum) -> Result<Vec<Arm<'_>>> {
+    data_enum
         .variants
         .iter()
         .map(|v| {
-            if !matches!(v.fields, syn::Fields::Unit) {
-                return Err(syn::Error::new_spanned(
-                    &v.fields,
-                    "Enumable can only be derived for unit variants",
-                ));
+            let field = match &v.fields {
+                Fields::Unit => None,
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+                Fields::Named(fields) if fields.named.len() == 1 => fields.named.first(),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &v.fields,
+                        "Enumable can only be derived for unit variants or variants with a single Enumable field",
+                    ));
+                }
+            };
+            Ok(Arm {
+                ident: &v.ident,
+                payload: field.map(|f| Payload {
+                    name: f.ident.as_ref(),
+                    ty: &f.ty,
+                }),
+            })
+        })
+        .collect()
+}
+
+fn derive_data_enum(ident: &Ident, data_enum: &DataEnum) -> Result<TokenStream> {
+    if let Some(attr) = data_enum
+        .variants
+        .iter()
+        .find_map(|v| VariantAttrs::find(&v.attrs))
+    {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "only enums with unit variants alone accept options on their variants",
+        ));
+    }
+    let arms = parse_arms(data_enum)?;
+    let self_ty = quote! { Self };
+    let ident_ty = quote! { #ident };
+
+    // `bases[i]` is the index in `VARIANTS` of the first entry contributed by arm `i`,
+    // and the final element is the total count.
+    let mut bases = vec![quote! { 0usize }];
+    for arm in &arms {
+        let previous = bases.last().cloned().unwrap_or_default();
+        let count = arm.count();
+        bases.push(quote! { (#previous + #count) });
+    }
+    let count = bases.last().cloned().unwrap_or_default();
+
+    let push_variants = arms.iter().map(|arm| match &arm.payload {
+        Some(Payload { ty, .. }) => {
+            let construct = arm.pattern(&self_ty);
+            quote! {
+                let mut i = 0;
+                while i < <#ty as enum_table::Enumable>::COUNT {
+                    let value = <#ty as enum_table::Enumable>::VARIANTS[i];
+                    builder.push(#construct);
+                    i += 1;
+                }
             }
-            Ok(&v.ident)
+        }
+        None => {
+            let construct = arm.pattern(&self_ty);
+            quote! { builder.push(#construct); }
+        }
+    });
+
+    let index_arms = arms.iter().zip(&bases).map(|(arm, base)| {
+        let pattern = arm.pattern(&self_ty);
+        match &arm.payload {
+            Some(_) => quote! { #pattern => #base + enum_table::Enumable::variant_index(&value), },
+            None => quote! { #pattern => #base, },
+        }
+    });
+
+    let layout_arms = arms.iter().zip(&bases).map(|(arm, base)| {
+        let count = arm.count();
+        match &arm.payload {
+            Some(Payload { ty, .. }) => {
+                let pattern = arm.pattern(&ident_ty);
+                quote! {
+                    enum_table::__private::Arm {
+                        base: #base,
+                        count: #count,
+                        offset: if #count == 0 {
+                            0
+                        } else {
+                            let variant = &<#ident as enum_table::Enumable>::VARIANTS[#base];
+                            match variant {
+                                // SAFETY: `value` is a field of `variant`.
+                                #pattern => unsafe { enum_table::__private::payload_offset(variant, value) },
+                                _ => 0,
+                            }
+                        },
+                        size: core::mem::size_of::<#ty>(),
+                        layout: Some(&<#ty as enum_table::Enumable>::LAYOUT),
+                    },
+                }
+            }
+            None => quote! {
+                enum_table::__private::Arm {
+                    base: #base,
+                    count: #count,
+                    offset: 0,
+                    size: 0,
+                    layout: None,
+                },
+            },
+        }
+    });
+
+    Ok(quote! {
+        impl enum_table::Enumable for #ident {
+            const VARIANTS: &'static [#ident] = &{
+                let mut builder = enum_table::__private::ArrayBuilder::<#ident, { #count }>::new();
+                #(#push_variants)*
+                builder.build()
+            };
+
+            const COUNT: usize = #count;
+
+            const LAYOUT: enum_table::layout::Layout = {
+                const ARMS: &[enum_table::__private::Arm] = &[#(#layout_arms)*];
+                // SAFETY: `ARMS` describes every variant in the order of `VARIANTS`, and the
+                // payload offsets are measured on actual values.
+                unsafe { enum_table::layout::Layout::variants::<#ident>(ARMS) }
+            };
+
+            fn variant_index(&self) -> usize {
+                match *self {
+                    #(#index_arms)*
+                }
+            }
+        }
+    })
+}
+
+fn derive_struct(ident: &Ident, data_struct: &DataStruct) -> TokenStream {
+    let members = data_struct
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| match &f.ident {
+            Some(name) => Member::Named(name.clone()),
+            None => Member::Unnamed(Index::from(i)),
         })
-        .collect::<Result<Vec<_>>>()?;
+        .collect::<Vec<_>>();
+    let types = data_struct.fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let counts = types
+        .iter()
+        .map(|ty| quote! { <#ty as enum_table::Enumable>::COUNT })
+        .collect::<Vec<_>>();
+    let count = quote! { (1usize #(* #counts)*) };
+
+    // `VARIANTS` is the cartesian product of the fields with the first field most
+    // significant, so each entry is found by splitting its index into mixed-radix digits.
+    let values = (0..types.len())
+        .map(|i| quote::format_ident!("field{}", i))
+        .collect::<Vec<_>>();
+    let digits = (0..types.len()).rev().map(|i| {
+        let value = &values[i];
+        let ty = types[i];
+        let count = &counts[i];
+        if i == 0 {
+            quote! { let #value = <#ty as enum_table::Enumable>::VARIANTS[rest]; }
+        } else {
+            quote! {
+                let #value = <#ty as enum_table::Enumable>::VARIANTS[rest % #count];
+                rest /= #count;
+            }
+        }
+    });
+    let rest = match types.len() {
+        0 => quote! {},
+        1 => quote! { let rest = i; },
+        _ => quote! { let mut rest = i; },
+    };
+    let construct = match &data_struct.fields {
+        Fields::Named(_) => quote! { Self { #(#members: #values),* } },
+        Fields::Unnamed(_) => quote! { Self(#(#values),*) },
+        Fields::Unit => quote! { Self },
+    };
+
+    let index = members
+        .iter()
+        .zip(&counts)
+        .map(|(member, count)| {
+            (
+                count,
+                quote! { enum_table::Enumable::variant_index(&self.#member) },
+            )
+        })
+        .reduce(|(_, acc), (count, digit)| (count, quote! { (#acc * #count + #digit) }))
+        .map_or_else(|| quote! { 0 }, |(_, index)| index);
+
+    let layout_fields = members.iter().zip(&types).map(|(member, ty)| {
+        quote! {
+            enum_table::__private::Field {
+                offset: core::mem::offset_of!(#ident, #member),
+                count: <#ty as enum_table::Enumable>::COUNT,
+                size: core::mem::size_of::<#ty>(),
+                layout: &<#ty as enum_table::Enumable>::LAYOUT,
+            },
+        }
+    });
+
+    quote! {
+        impl enum_table::Enumable for #ident {
+            const VARIANTS: &'static [#ident] = &{
+                let mut builder = enum_table::__private::ArrayBuilder::<#ident, { #count }>::new();
+                let mut i = 0;
+                while i < #count {
+                    #rest
+                    #(#digits)*
+                    builder.push(#construct);
+                    i += 1;
+                }
+                builder.build()
+            };
+
+            const COUNT: usize = #count;
+
+            const LAYOUT: enum_table::layout::Layout = {
+                const FIELDS: &[enum_table::__private::Field] = &[#(#layout_fields)*];
+                // SAFETY: `FIELDS` describes every field in declaration order, matching the
+                // mixed-radix order of `VARIANTS`.
+                unsafe { enum_table::layout::Layout::product(FIELDS) }
+            };
+
+            fn variant_index(&self) -> usize {
+                #index
+            }
+        }
+    }
+}
 
+/// Derives `Enumable` for a struct holding a single integer restricted to `range`, whose
+/// `VARIANTS` are the values of the range in increasing order.
+fn derive_int_newtype(input: &DeriveInput, range: &IntRange) -> Result<TokenStream> {
     let ident = &input.ident;
-    let expanded = quote! {
+    let error = || {
+        syn::Error::new_spanned(
+            input,
+            "`range` requires a struct with a single field of a primitive integer type",
+        )
+    };
+    let Data::Struct(data_struct) = &input.data else {
+        return Err(error());
+    };
+    let mut fields = data_struct.fields.iter();
+    let (Some(field), None) = (fields.next(), fields.next()) else {
+        return Err(error());
+    };
+    let int = match &field.ty {
+        Type::Path(ty) if ty.qself.is_none() => ty.path.get_ident().cloned(),
+        _ => None,
+    }
+    .filter(|int| INTS.contains(&int.to_string().as_str()))
+    .ok_or_else(error)?;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Enumable cannot be derived for generic structs",
+        ));
+    }
+
+    let member = match &field.ident {
+        Some(name) => Member::Named(name.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+    let construct = match &field.ident {
+        Some(name) => quote! { #ident { #name: value } },
+        None => quote! { #ident(value) },
+    };
+    let count = range.count()?;
+    let start = int_literal(range.start);
+    let last = int_literal(range.last);
+    let range_text = format!("{}..={}", range.start, range.last);
+    let layout = if int.to_string().starts_with('i') {
+        quote! { enum_table::layout::Layout::signed::<#ident>() }
+    } else {
+        quote! { enum_table::layout::Layout::sorted::<#ident>() }
+    };
+    let vis = &input.vis;
+    let new_doc = format!(
+        " Returns the value holding `value`, or `None` if `value` is outside `{range_text}`."
+    );
+    let index_doc = format!(
+        " Panics if the value is outside `{range_text}`, which [`{ident}::new`] rules out."
+    );
+    // Out-of-range arms are unreachable when the range covers the whole type.
+    let (reject, out_of_range) = if int_bounds(&int) == Some((range.start, range.last)) {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        (
+            quote! { _ => None, },
+            quote! {
+                value => panic!("enum-table: {} is outside the range {} of `{}`", value, #range_text, stringify!(#ident)),
+            },
+        )
+    };
+
+    Ok(quote! {
+        // Literal patterns out of range for `#int` are not reported in macro output.
+        const _: () = {
+            let (start, last): (i128, i128) = (#start, #last);
+            assert!(
+                #int::MIN as i128 <= start && (last < 0 || last as u128 <= #int::MAX as u128),
+                concat!("enum-table: the range ", #range_text, " does not fit in `", stringify!(#int), "`"),
+            );
+        };
+
         impl enum_table::Enumable for #ident {
-            const VARIANTS: &'static [#ident] = &enum_table::__private::sort_variants([#(Self::#variant_idents),*]);
+            const VARIANTS: &'static [#ident] = &{
+                let mut builder = enum_table::__private::ArrayBuilder::<#ident, #count>::new();
+                let mut i = 0;
+                while i < #count {
+                    let value = (#start + i as i128) as #int;
+                    builder.push(#construct);
+                    i += 1;
+                }
+                builder.build()
+            };
+
+            const COUNT: usize = #count;
 
+            const LAYOUT: enum_table::layout::Layout = #layout;
+
+            /// Returns the value minus the start of the range.
+            ///
+            /// # Panics
+            ///
+            #[doc = #index_doc]
             fn variant_index(&self) -> usize {
-                match *self {
-                    #(
-                        Self::#variant_idents => const { enum_table::__private::variant_index_of(&#ident::#variant_idents, <#ident as enum_table::Enumable>::VARIANTS) },
-                    )*
+                match self.#member {
+                    #start..=#last => (self.#member as i128 - #start) as usize,
+                    #out_of_range
+                }
+            }
+        }
+
+        impl #ident {
+            #[doc = #new_doc]
+            #vis const fn new(value: #int) -> Option<Self> {
+                match value {
+                    #start..=#last => Some(#construct),
+                    #reject
                 }
             }
         }
+    })
+}
+
+/// Returns the smallest and largest values of a fixed-size primitive integer type.
+fn int_bounds(int: &Ident) -> Option<(i128, i128)> {
+    Some(match int.to_string().as_str() {
+        "i8" => (i8::MIN.into(), i8::MAX.into()),
+        "i16" => (i16::MIN.into(), i16::MAX.into()),
+        "i32" => (i32::MIN.into(), i32::MAX.into()),
+        "i64" => (i64::MIN.into(), i64::MAX.into()),
+        "u8" => (u8::MIN.into(), u8::MAX.into()),
+        "u16" => (u16::MIN.into(), u16::MAX.into()),
+        "u32" => (u32::MIN.into(), u32::MAX.into()),
+        "u64" => (u64::MIN.into(), u64::MAX.into()),
+        _ => return None,
+    })
+}
+
+/// Returns an unsuffixed integer literal, preceded by `-` if negative.
+fn int_literal(value: i128) -> TokenStream {
+    let literal = proc_macro2::Literal::u128_unsuffixed(value.unsigned_abs());
+    if value < 0 {
+        quote! { -#literal }
+    } else {
+        quote! { #literal }
+    }
+}
+
+fn derive_names(
+    ident: &Ident,
+    data_enum: &DataEnum,
+    attrs: &ContainerAttrs,
+    signed: &TokenStream,
+) -> Result<TokenStream> {
+    let mut names = Vec::new();
+    let mut docs = Vec::new();
+    // Every name accepted by `from_name`, with the declaration ordinal of its variant.
+    let mut lookup = Vec::<(String, usize)>::new();
+    for (ordinal, v) in data_enum.variants.iter().enumerate() {
+        let variant_attrs = VariantAttrs::parse(&v.attrs)?;
+        let name = match &variant_attrs.rename {
+            Some(rename) => rename.value(),
+            None => match attrs.rename_all {
+                Some(rule) => rule.apply(&v.ident.to_string()),
+                None => v.ident.to_string(),
+            },
+        };
+        let aliases = variant_attrs.aliases.iter().map(LitStr::value);
+        for key in std::iter::once(name.clone()).chain(aliases) {
+            let key = if attrs.ascii_case_insensitive {
+                key.to_ascii_lowercase()
+            } else {
+                key
+            };
+            if lookup.iter().any(|(other, _)| *other == key) {
+                return Err(syn::Error::new_spanned(
+                    &v.ident,
+                    format!("duplicate variant name `{key}`"),
+                ));
+            }
+            lookup.push((key, ordinal));
+        }
+        names.push(name);
+        docs.push(attrs::doc_comment(&v.attrs));
+    }
+    lookup.sort();
+
+    // Both are declared in the order of the variants, so they are permuted like
+    // `VARIANTS` when that is sorted by discriminant.
+    let count = names.len();
+    let variant_idents = data_enum
+        .variants
+        .iter()
+        .map(|v| &v.ident)
+        .collect::<Vec<_>>();
+    let in_variant_order = |values: &[String]| match attrs.order {
+        Order::Discriminant if count > 0 => quote! {{
+            const POSITIONS: [usize; #count] = enum_table::__private::sorted_positions::<#count, #ident>(
+                [#(#ident::#variant_idents as u128),*],
+                #signed,
+            );
+            enum_table::__private::permute([#(#values),*], &POSITIONS)
+        }},
+        _ => quote! { [#(#values),*] },
+    };
+    let names = in_variant_order(&names);
+    // Skip sorting when there is nothing to sort, which is cheaper in large enums.
+    let docs = if docs.iter().all(String::is_empty) {
+        quote! { [""; #count] }
+    } else {
+        in_variant_order(&docs)
     };
 
-    Ok(expanded)
+    let keys = lookup.iter().map(|(key, _)| key);
+    let key_ordinals = lookup.iter().map(|(_, ordinal)| ordinal);
+    let ordinals = 0..count;
+    let ignore_case = attrs.ascii_case_insensitive;
+
+    Ok(quote! {
+        impl enum_table::EnumableNames for #ident {
+            const NAMES: &'static [&'static str] = &#names;
+            const DOCS: &'static [&'static str] = &#docs;
+
+            fn from_name(name: &str) -> Option<Self> {
+                // Sorted by name for a binary search. Variants are referred to by their
+                // declaration ordinal, which needs no `const` evaluation.
+                const LOOKUP: &[(&str, usize)] = &[#((#keys, #key_ordinals)),*];
+                match enum_table::__private::find_name(LOOKUP, name, #ignore_case)? {
+                    #( #ordinals => Some(Self::#variant_idents), )*
+                    _ => None,
+                }
+            }
+        }
+
+        impl core::str::FromStr for #ident {
+            type Err = enum_table::ParseNameError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                <Self as enum_table::EnumableNames>::from_name(name)
+                    .ok_or_else(enum_table::ParseNameError::new::<Self>)
+            }
+        }
+    })
+}
+
+/// The associated constants of `Enumable` and `EnumableNames`, which an inherent constant of
+/// the same name would shadow.
+const RESERVED_GROUP_NAMES: &[&str] = &["VARIANTS", "COUNT", "LAYOUT", "NAMES", "DOCS"];
+
+/// Generates a constant listing the variants of each `#[enumable(group = "...")]`, named
+/// after the group in SCREAMING_SNAKE_CASE.
+fn derive_groups(
+    ident: &Ident,
+    vis: &Visibility,
+    data_enum: &DataEnum,
+    order: Order,
+    signed: &TokenStream,
+) -> Result<TokenStream> {
+    let mut groups = Vec::<(LitStr, Vec<&Ident>)>::new();
+    // The constants generated by `derive_props`, which the group constants must not repeat.
+    let mut prop_tables = Vec::<String>::new();
+    for v in &data_enum.variants {
+        let variant_attrs = VariantAttrs::parse(&v.attrs)?;
+        for group in variant_attrs.groups {
+            match groups
+                .iter_mut()
+                .find(|(other, _)| other.value() == group.value())
+            {
+                Some((_, variants)) => variants.push(&v.ident),
+                None => groups.push((group, vec![&v.ident])),
+            }
+        }
+        prop_tables.extend(
+            variant_attrs
+                .props
+                .iter()
+                .map(|(key, _)| prop_table_name(key)),
+        );
+    }
+
+    let mut const_names = Vec::<(String, &LitStr)>::new();
+    let consts = groups
+        .iter()
+        .map(|(group, variant_idents)| {
+            let name = group.value();
+            let const_ident = syn::parse_str::<Ident>(&name.to_ascii_uppercase().replace('-', "_"))
+                .map_err(|_| syn::Error::new_spanned(group, "group names must be identifiers"))?;
+            let const_name = const_ident.to_string();
+            if RESERVED_GROUP_NAMES.contains(&const_name.as_str()) {
+                return Err(syn::Error::new_spanned(
+                    group,
+                    format!(
+                        "the group constant `{const_ident}` would shadow the associated constant of the same name"
+                    ),
+                ));
+            }
+            if let Some((_, other)) = const_names.iter().find(|(other, _)| *other == const_name) {
+                return Err(syn::Error::new_spanned(
+                    group,
+                    format!(
+                        "the groups `{}` and `{name}` are both named `{const_ident}`",
+                        other.value()
+                    ),
+                ));
+            }
+            if prop_tables.contains(&const_name) {
+                return Err(syn::Error::new_spanned(
+                    group,
+                    format!("the group constant `{const_ident}` is also the table of a property"),
+                ));
+            }
+            const_names.push((const_name, group));
+            let doc = format!(" The variants in the `{name}` group, in the order of `VARIANTS`.");
+            let variants = match order {
+                Order::Discriminant => quote! {
+                    if #signed {
+                        enum_table::__private::sort_signed_variants([#(Self::#variant_idents),*])
+                    } else {
+                        enum_table::__private::sort_variants([#(Self::#variant_idents),*])
+                    }
+                },
+                Order::Declaration => quote! { [#(Self::#variant_idents),*] },
+            };
+            Ok(quote! {
+                #[doc = #doc]
+                #vis const #const_ident: &'static [#ident] = &#variants;
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if consts.is_empty() {
+        return Ok(TokenStream::new());
+    }
+    Ok(quote! {
+        impl #ident {
+            #(#consts)*
+        }
+    })
+}
+
+/// Returns the name of the constant holding the table of the property `key`, so that
+/// `r#type` names `TYPE_TABLE`.
+fn prop_table_name(key: &Ident) -> String {
+    format!("{}_TABLE", key.unraw().to_string().to_ascii_uppercase())
+}
+
+/// Generates a `const` `EnumTable` named `<PROPERTY>_TABLE` for each property given with
+/// `#[enumable(prop(...))]`, which every variant must have.
+fn derive_props(
+    ident: &Ident,
+    vis: &Visibility,
+    data_enum: &DataEnum,
+    order: Order,
+    signed: &TokenStream,
+) -> Result<TokenStream> {
+    let variant_props = data_enum
+        .variants
+        .iter()
+        .map(|v| Ok(VariantAttrs::parse(&v.attrs)?.props))
+        .collect::<Result<Vec<_>>>()?;
+    let mut keys = Vec::<&Ident>::new();
+    for (key, _) in variant_props.iter().flatten() {
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    if keys.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let count = data_enum.variants.len();
+    let variant_idents = data_enum.variants.iter().map(|v| &v.ident);
+    let positions = quote! {
+        const POSITIONS: [usize; #count] = enum_table::__private::sorted_positions::<#count, #ident>(
+            [#(#ident::#variant_idents as u128),*],
+            #signed,
+        );
+    };
+
+    let tables = keys
+        .iter()
+        .map(|key| {
+            let values = data_enum
+                .variants
+                .iter()
+                .zip(&variant_props)
+                .map(|(v, props)| {
+                    match props.iter().find(|(other, _)| other == *key) {
+                        Some((_, value)) => Ok(value),
+                        None => Err(syn::Error::new_spanned(
+                            &v.ident,
+                            format!("missing property `{key}`"),
+                        )),
+                    }
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let ty = prop_type(&values).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    key,
+                    "cannot infer the type of this property, give a literal value to at least one variant",
+                )
+            })?;
+
+            // Declared in the order of the variants, then permuted like `VARIANTS`.
+            let array = match order {
+                Order::Discriminant => quote! {{
+                    #positions
+                    enum_table::__private::permute::<#count, #ty>([#(#values),*], &POSITIONS)
+                }},
+                Order::Declaration => quote! { [#(#values),*] },
+            };
+            let const_ident = quote::format_ident!("{}", prop_table_name(key));
+            let doc = format!(" The `{}` property of each variant.", key.unraw());
+            Ok(quote! {
+                #[doc = #doc]
+                #vis const #const_ident: enum_table::EnumTable<#ident, #ty, #count> =
+                    enum_table::__private::table_from_array(#array);
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl #ident {
+            #(#tables)*
+        }
+    })
+}
+
+/// Infers the type of a property from the first literal among its values, preferring
+/// literals with a suffix such as `3u8`.
+fn prop_type(values: &[&syn::Expr]) -> Option<TokenStream> {
+    fn literal(expr: &syn::Expr) -> Option<&syn::Lit> {
+        match expr {
+            syn::Expr::Lit(expr) => Some(&expr.lit),
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => literal(expr),
+            syn::Expr::Group(expr) => literal(&expr.expr),
+            syn::Expr::Paren(expr) => literal(&expr.expr),
+            _ => None,
+        }
+    }
+
+    let mut ty = None;
+    for lit in values.iter().filter_map(|value| literal(value)) {
+        let (suffix, default) = match lit {
+            syn::Lit::Str(_) => return Some(quote! { &'static str }),
+            syn::Lit::ByteStr(_) => return Some(quote! { &'static [u8] }),
+            syn::Lit::Byte(_) => return Some(quote! { u8 }),
+            syn::Lit::Char(_) => return Some(quote! { char }),
+            syn::Lit::Bool(_) => return Some(quote! { bool }),
+            syn::Lit::Int(int) => (int.suffix(), quote! { i32 }),
+            syn::Lit::Float(float) => (float.suffix(), quote! { f64 }),
+            _ => continue,
+        };
+        if !suffix.is_empty() {
+            let suffix = Ident::new(suffix, lit.span());
+            return Some(quote! { #suffix });
+        }
+        ty.get_or_insert(default);
+    }
+    ty
+}
+
+fn derive_repr(ident: &Ident, repr: &Ident) -> TokenStream {
+    quote! {
+        impl enum_table::EnumableRepr for #ident {
+            type Repr = #repr;
+
+            fn from_discriminant(raw: #repr) -> Option<Self> {
+                // SAFETY: `#repr` is the primitive representation of `Self`.
+                unsafe { enum_table::__private::variant_from_discriminant(&raw) }
+            }
+
+            fn discriminant(&self) -> #repr {
+                *self as #repr
+            }
+        }
+    }
 }
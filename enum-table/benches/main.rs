@@ -1,4 +1,4 @@
-use std::{collections::HashMap, hash::Hash};
+use std::{collections::HashMap, hash::Hash, hint::black_box};
 
 use criterion::{Criterion, criterion_group, criterion_main};
 use enum_table::{EnumTable, Enumable};
@@ -14,6 +14,52 @@ enum Letter {
     G,
 }
 
+/// Declares a 300-variant opcode enum whose discriminants start at `$first`.
+macro_rules! opcodes {
+    ($name:ident = $first:literal) => {
+        opcodes!(@declare $name = $first;
+            Op000 Op001 Op002 Op003 Op004 Op005 Op006 Op007 Op008 Op009 Op010 Op011
+            Op012 Op013 Op014 Op015 Op016 Op017 Op018 Op019 Op020 Op021 Op022 Op023
+            Op024 Op025 Op026 Op027 Op028 Op029 Op030 Op031 Op032 Op033 Op034 Op035
+            Op036 Op037 Op038 Op039 Op040 Op041 Op042 Op043 Op044 Op045 Op046 Op047
+            Op048 Op049 Op050 Op051 Op052 Op053 Op054 Op055 Op056 Op057 Op058 Op059
+            Op060 Op061 Op062 Op063 Op064 Op065 Op066 Op067 Op068 Op069 Op070 Op071
+            Op072 Op073 Op074 Op075 Op076 Op077 Op078 Op079 Op080 Op081 Op082 Op083
+            Op084 Op085 Op086 Op087 Op088 Op089 Op090 Op091 Op092 Op093 Op094 Op095
+            Op096 Op097 Op098 Op099 Op100 Op101 Op102 Op103 Op104 Op105 Op106 Op107
+            Op108 Op109 Op110 Op111 Op112 Op113 Op114 Op115 Op116 Op117 Op118 Op119
+            Op120 Op121 Op122 Op123 Op124 Op125 Op126 Op127 Op128 Op129 Op130 Op131
+            Op132 Op133 Op134 Op135 Op136 Op137 Op138 Op139 Op140 Op141 Op142 Op143
+            Op144 Op145 Op146 Op147 Op148 Op149 Op150 Op151 Op152 Op153 Op154 Op155
+            Op156 Op157 Op158 Op159 Op160 Op161 Op162 Op163 Op164 Op165 Op166 Op167
+            Op168 Op169 Op170 Op171 Op172 Op173 Op174 Op175 Op176 Op177 Op178 Op179
+            Op180 Op181 Op182 Op183 Op184 Op185 Op186 Op187 Op188 Op189 Op190 Op191
+            Op192 Op193 Op194 Op195 Op196 Op197 Op198 Op199 Op200 Op201 Op202 Op203
+            Op204 Op205 Op206 Op207 Op208 Op209 Op210 Op211 Op212 Op213 Op214 Op215
+            Op216 Op217 Op218 Op219 Op220 Op221 Op222 Op223 Op224 Op225 Op226 Op227
+            Op228 Op229 Op230 Op231 Op232 Op233 Op234 Op235 Op236 Op237 Op238 Op239
+            Op240 Op241 Op242 Op243 Op244 Op245 Op246 Op247 Op248 Op249 Op250 Op251
+            Op252 Op253 Op254 Op255 Op256 Op257 Op258 Op259 Op260 Op261 Op262 Op263
+            Op264 Op265 Op266 Op267 Op268 Op269 Op270 Op271 Op272 Op273 Op274 Op275
+            Op276 Op277 Op278 Op279 Op280 Op281 Op282 Op283 Op284 Op285 Op286 Op287
+            Op288 Op289 Op290 Op291 Op292 Op293 Op294 Op295 Op296 Op297 Op298 Op299
+        );
+    };
+    (@declare $name:ident = $first:literal; $head:ident $($tail:ident)*) => {
+        #[derive(Clone, Copy, Enumable, Eq, PartialEq)]
+        #[repr(u16)]
+        enum $name {
+            $head = $first,
+            $($tail,)*
+        }
+    };
+}
+
+// Discriminants contiguous from 0: `variant_index` is `*self as usize`.
+opcodes!(Opcode = 0);
+// Discriminants contiguous from 1: `variant_index` is a `match` over every variant.
+opcodes!(OffsetOpcode = 1);
+
 fn new() -> EnumTable<Letter, &'static str, { Letter::COUNT }> {
     EnumTable::new_with_fn(|letter| match letter {
         Letter::A => "Alpha",
@@ -83,6 +129,29 @@ fn hash_map_set(criterion: &mut Criterion) {
     });
 }
 
+fn large_enum_get(criterion: &mut Criterion) {
+    let table = EnumTable::<Opcode, u32, { Opcode::COUNT }>::new_with_fn(|op| *op as u32);
+    criterion.bench_function("EnumTable::get (300 variants, from 0)", |bencher| {
+        bencher.iter(|| {
+            Opcode::VARIANTS
+                .iter()
+                .map(|op| *table.get(black_box(op)))
+                .sum::<u32>()
+        })
+    });
+
+    let table =
+        EnumTable::<OffsetOpcode, u32, { OffsetOpcode::COUNT }>::new_with_fn(|op| *op as u32);
+    criterion.bench_function("EnumTable::get (300 variants, from 1)", |bencher| {
+        bencher.iter(|| {
+            OffsetOpcode::VARIANTS
+                .iter()
+                .map(|op| *table.get(black_box(op)))
+                .sum::<u32>()
+        })
+    });
+}
+
 criterion_group!(
     benches,
     enum_table_new_with_fn,
@@ -90,5 +159,6 @@ criterion_group!(
     hash_map_get,
     enum_table_set,
     hash_map_set,
+    large_enum_get,
 );
 criterion_main!(benches);
@@ -69,7 +69,7 @@ impl<K: Enumable, V, const N: usize> EnumTableBuilder<K, V, N> {
     /// # Safety
     ///
     /// * The caller must ensure that elements are pushed in the correct order
-    ///   (sorted by discriminant).
+    ///   (the order of [`Enumable::VARIANTS`]).
     /// * The caller must ensure that no variant is pushed more than once.
     /// * The caller must ensure that the builder doesn't exceed capacity N.
     ///
@@ -124,8 +124,8 @@ impl<K: Enumable, V, const N: usize> EnumTableBuilder<K, V, N> {
         {
             let keys = unsafe { self.keys.assume_init() };
             assert!(
-                crate::intrinsics::is_sorted(&keys),
-                "EnumTableBuilder: elements are not sorted by discriminant. Ensure that the elements are pushed in the correct order."
+                crate::intrinsics::is_variant_order(&keys),
+                "EnumTableBuilder: elements are not in the order of VARIANTS. Ensure that the elements are pushed in the correct order."
             );
         }
 
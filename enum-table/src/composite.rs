@@ -0,0 +1,404 @@
+//! The tables of keys made of other keys: `Option`, `Result` and tuples.
+//!
+//! The variant count of such a key depends on its parameters, such as `K::COUNT + 1` for
+//! `Option<K>`, which stable Rust cannot use as an array length in an associated type. Its
+//! [`EnumableTable::Table`](crate::EnumableTable::Table) is a [`CompositeTable`] instead,
+//! which stores the tables of the parts side by side, so that the values are still laid out
+//! as an array in the order of [`Enumable::VARIANTS`].
+
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ops::{Index, IndexMut};
+
+use crate::{AnyEnumTable, EnumTable, Enumable};
+
+pub(crate) mod private {
+    pub trait Sealed {}
+
+    /// A type laid out as `[V; Self::LEN]`, which owns the values.
+    pub trait Storage<V>: Sized {
+        const LEN: usize;
+    }
+}
+
+use private::Storage;
+
+impl<V, const N: usize> Storage<V> for [V; N] {
+    const LEN: usize = N;
+}
+
+/// `EnumTable` is `repr(transparent)` over its array.
+impl<K: Enumable, V, const N: usize> Storage<V> for EnumTable<K, V, N> {
+    const LEN: usize = N;
+}
+
+/// A key made of other keys, whose table is a [`CompositeTable`].
+///
+/// This trait is sealed, and implemented for `Option`, `Result`, pairs and triples of
+/// [`EnumableTable`](crate::EnumableTable) keys.
+pub trait CompositeKey: Enumable + private::Sealed {
+    /// The tables of the parts of `Self`, laid out as `[V; Self::COUNT]`.
+    type Storage<V>: Storage<V>;
+}
+
+/// `A` followed by `B`: the storage of `Option` and `Result` keys, and of their bitmasks.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Concat<A, B>(pub(crate) A, pub(crate) B);
+
+impl<V, A: Storage<V>, B: Storage<V>> Storage<V> for Concat<A, B> {
+    const LEN: usize = A::LEN + B::LEN;
+}
+
+/// The rows `R`, each of which is a `W`: the storage of tuple keys.
+#[repr(transparent)]
+pub struct Rows<R, W>(R, PhantomData<W>);
+
+impl<R: Clone, W> Clone for Rows<R, W> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<R: Copy, W> Copy for Rows<R, W> {}
+
+impl<V, R: Storage<W>, W: Storage<V>> Storage<V> for Rows<R, W> {
+    const LEN: usize = R::LEN * W::LEN;
+}
+
+/// The table of a [`CompositeKey`], with a value of type `V` for each variant of `K`.
+///
+/// This is the [`EnumTableOf<K, V>`](crate::EnumTableOf) of `Option`, `Result` and tuple
+/// keys, and is used through [`AnyEnumTable`].
+///
+/// # Examples
+///
+/// ```rust
+/// use enum_table::{AnyEnumTable, EnumTableOf, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Coin {
+///     Heads,
+///     Tails,
+/// }
+///
+/// let mut table = EnumTableOf::<Option<Coin>, u32>::new_with_fn(|coin| match coin {
+///     None => 0,
+///     Some(_) => 1,
+/// });
+/// table[Some(Coin::Tails)] += 1;
+/// assert_eq!(table.as_slice(), [0, 1, 2]);
+/// ```
+#[repr(transparent)]
+pub struct CompositeTable<K: CompositeKey, V> {
+    values: K::Storage<V>,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: CompositeKey, V> CompositeTable<K, V> {
+    /// The number of values, one for each variant.
+    const LEN: usize = {
+        assert!(
+            <K::Storage<V> as Storage<V>>::LEN == K::COUNT,
+            "CompositeTable: the tables of the parts must have a value for each of their variants."
+        );
+        K::COUNT
+    };
+
+    /// Creates a table from a function of the index of each variant, stopping at the first
+    /// error.
+    pub(crate) fn try_from_fn<E>(mut f: impl FnMut(usize) -> Result<V, E>) -> Result<Self, E> {
+        let mut values = MaybeUninit::<K::Storage<V>>::uninit();
+        let ptr = values.as_mut_ptr().cast::<V>();
+
+        for initialized in 0..Self::LEN {
+            match f(initialized) {
+                // SAFETY: the storage is laid out as `[V; Self::LEN]`.
+                Ok(value) => unsafe { ptr.add(initialized).write(value) },
+                Err(e) => {
+                    // SAFETY: the values before `initialized` were written above.
+                    unsafe {
+                        core::ptr::slice_from_raw_parts_mut(ptr, initialized).drop_in_place();
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(Self {
+            // SAFETY: all the values have been written in the loop above.
+            values: unsafe { values.assume_init() },
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<K: CompositeKey, V> Storage<V> for CompositeTable<K, V> {
+    const LEN: usize = Self::LEN;
+}
+
+impl<K: CompositeKey, V> AnyEnumTable<K, V> for CompositeTable<K, V> {
+    fn new_with_fn(mut f: impl FnMut(&K) -> V) -> Self {
+        let variants = &K::VARIANTS[..Self::LEN];
+        match Self::try_from_fn(|i| Ok::<V, core::convert::Infallible>(f(&variants[i]))) {
+            Ok(table) => table,
+            Err(never) => match never {},
+        }
+    }
+
+    fn get(&self, variant: &K) -> &V {
+        &self.as_slice()[variant.variant_index()]
+    }
+
+    fn get_mut(&mut self, variant: &K) -> &mut V {
+        &mut self.as_mut_slice()[variant.variant_index()]
+    }
+
+    fn set(&mut self, variant: &K, value: V) -> V {
+        core::mem::replace(self.get_mut(variant), value)
+    }
+
+    fn as_slice(&self) -> &[V] {
+        // SAFETY: the storage is laid out as `[V; Self::LEN]`.
+        unsafe { core::slice::from_raw_parts((&raw const self.values).cast::<V>(), Self::LEN) }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [V] {
+        // SAFETY: see `as_slice`.
+        unsafe { core::slice::from_raw_parts_mut((&raw mut self.values).cast::<V>(), Self::LEN) }
+    }
+}
+
+impl<K: CompositeKey + core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug
+    for CompositeTable<K, V>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: CompositeKey, V: Clone> Clone for CompositeTable<K, V> {
+    fn clone(&self) -> Self {
+        let values = self.as_slice();
+        Self::new_with_fn(|variant| values[variant.variant_index()].clone())
+    }
+}
+
+impl<K: CompositeKey, V: Copy> Copy for CompositeTable<K, V> where K::Storage<V>: Copy {}
+
+impl<K: CompositeKey, V: PartialEq> PartialEq for CompositeTable<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<K: CompositeKey, V: Eq> Eq for CompositeTable<K, V> {}
+
+impl<K: CompositeKey, V: core::hash::Hash> core::hash::Hash for CompositeTable<K, V> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<K: CompositeKey, V: Default> Default for CompositeTable<K, V> {
+    fn default() -> Self {
+        Self::new_with_fn(|_| V::default())
+    }
+}
+
+impl<K: CompositeKey, V> Index<K> for CompositeTable<K, V> {
+    type Output = V;
+
+    fn index(&self, index: K) -> &Self::Output {
+        self.get(&index)
+    }
+}
+
+impl<K: CompositeKey, V> IndexMut<K> for CompositeTable<K, V> {
+    fn index_mut(&mut self, index: K) -> &mut Self::Output {
+        self.get_mut(&index)
+    }
+}
+
+impl<K: CompositeKey, V> Index<&K> for CompositeTable<K, V> {
+    type Output = V;
+
+    fn index(&self, index: &K) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<K: CompositeKey, V> IndexMut<&K> for CompositeTable<K, V> {
+    fn index_mut(&mut self, index: &K) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<K: CompositeKey, V> IntoIterator for CompositeTable<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let table = ManuallyDrop::new(self);
+        IntoIter {
+            // SAFETY: `table` is not dropped, so its values are moved to the iterator.
+            values: ManuallyDrop::new(unsafe { core::ptr::read(&table.values) }),
+            next: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: CompositeKey, V> IntoIterator for &'a CompositeTable<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = core::iter::Zip<core::slice::Iter<'a, K>, core::slice::Iter<'a, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        K::VARIANTS.iter().zip(self.as_slice())
+    }
+}
+
+impl<'a, K: CompositeKey, V> IntoIterator for &'a mut CompositeTable<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = core::iter::Zip<core::slice::Iter<'a, K>, core::slice::IterMut<'a, V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        K::VARIANTS.iter().zip(self.as_mut_slice())
+    }
+}
+
+/// An owning iterator over the variants and values of a [`CompositeTable`].
+pub struct IntoIter<K: CompositeKey, V> {
+    values: ManuallyDrop<K::Storage<V>>,
+    /// The index of the first value that has not been moved out.
+    next: usize,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: CompositeKey, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == CompositeTable::<K, V>::LEN {
+            return None;
+        }
+        let variant = K::VARIANTS[self.next];
+        // SAFETY: the storage is laid out as `[V; LEN]`, and the value at `next` has not
+        // been moved out. Advancing `next` keeps it from being read or dropped again.
+        let value = unsafe { (&raw const self.values).cast::<V>().add(self.next).read() };
+        self.next += 1;
+        Some((variant, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = CompositeTable::<K, V>::LEN - self.next;
+        (len, Some(len))
+    }
+}
+
+impl<K: CompositeKey, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<K: CompositeKey, V> core::iter::FusedIterator for IntoIter<K, V> {}
+
+impl<K: CompositeKey, V> Drop for IntoIter<K, V> {
+    fn drop(&mut self) {
+        let rest = CompositeTable::<K, V>::LEN - self.next;
+        // SAFETY: the values from `next` on have not been moved out.
+        unsafe {
+            core::ptr::slice_from_raw_parts_mut(
+                (&raw mut self.values).cast::<V>().add(self.next),
+                rest,
+            )
+            .drop_in_place();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::EnumTableOf;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Color {
+        Red = 30,
+        Green = 10,
+        Blue = 20,
+    }
+
+    /// Checks that the value of each variant is at its index in `VARIANTS`.
+    fn assert_in_order<K: crate::EnumableTable>() {
+        let table = EnumTableOf::<K, usize>::new_with_fn(|variant| variant.variant_index());
+        assert_eq!(table.as_slice().len(), K::COUNT);
+        assert!(
+            table
+                .as_slice()
+                .iter()
+                .enumerate()
+                .all(|(i, index)| i == *index)
+        );
+        assert!(
+            K::VARIANTS
+                .iter()
+                .all(|variant| table[*variant] == variant.variant_index())
+        );
+    }
+
+    #[test]
+    fn values_follow_variants() {
+        assert_in_order::<Option<Color>>();
+        assert_in_order::<Option<Option<bool>>>();
+        assert_in_order::<Result<Color, bool>>();
+        assert_in_order::<(Color, bool)>();
+        assert_in_order::<(bool, Option<Color>, Color)>();
+        assert_in_order::<Result<(bool, bool), Option<Color>>>();
+    }
+
+    #[test]
+    fn accessors() {
+        let mut table = EnumTableOf::<Option<Color>, &str>::new_with_fn(|_| "");
+        assert_eq!(table.set(&Some(Color::Blue), "blue"), "");
+        table[None] = "none";
+        *table.get_mut(&Some(Color::Red)) = "red";
+        assert_eq!(table.as_slice(), ["none", "", "blue", "red"]);
+        assert_eq!(table.get(&Some(Color::Blue)), &"blue");
+        assert_eq!(
+            format!("{table:?}"),
+            r#"{None: "none", Some(Green): "", Some(Blue): "blue", Some(Red): "red"}"#
+        );
+
+        let copy = table;
+        assert_eq!(copy, table.clone());
+        assert_ne!(copy, CompositeTable::default());
+    }
+
+    #[test]
+    fn into_iter_drops_the_rest() {
+        let value = Rc::new(());
+        let table = EnumTableOf::<(bool, Color), Rc<()>>::new_with_fn(|_| value.clone());
+        assert_eq!(Rc::strong_count(&value), 7);
+
+        let mut iter = table.into_iter();
+        assert_eq!(iter.len(), 6);
+        let (variant, first) = iter.next().unwrap();
+        assert_eq!(variant, (false, Color::Green));
+        assert_eq!(iter.len(), 5);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 2);
+        drop(first);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn owned_iteration() {
+        let table = EnumTableOf::<Result<bool, Color>, usize>::new_with_fn(|variant| {
+            variant.variant_index() * 10
+        });
+        let entries: Vec<_> = table.into_iter().collect();
+        assert_eq!(entries[1], (Ok(true), 10));
+        assert_eq!(entries[4], (Err(Color::Red), 40));
+        assert_eq!(entries.len(), 5);
+    }
+}
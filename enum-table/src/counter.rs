@@ -0,0 +1,431 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{AnyEnumTable, EnumTable, EnumTableOf, Enumable, EnumableTable};
+
+impl<K: Enumable, const N: usize> EnumTable<K, u64, N> {
+    /// Counts the items of an iterator by the enumeration variant `key_fn` maps each of
+    /// them to.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`].
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to count.
+    /// * `key_fn` - A function that returns the variant of an item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use enum_table::{EnumTable, Enumable};
+    ///
+    /// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+    /// enum Length {
+    ///     Short,
+    ///     Long,
+    /// }
+    ///
+    /// let words = ["a", "table", "of", "enums"];
+    /// let table = EnumTable::<Length, u64, { Length::COUNT }>::histogram(words, |word| {
+    ///     if word.len() < 3 { Length::Short } else { Length::Long }
+    /// });
+    /// assert_eq!(table.as_slice(), &[2, 2]);
+    /// ```
+    pub fn histogram<T>(
+        items: impl IntoIterator<Item = T>,
+        mut key_fn: impl FnMut(&T) -> K,
+    ) -> Self {
+        let mut table = Self::new_fill_with_copy(0);
+        for item in items {
+            *table.get_mut(&key_fn(&item)) += 1;
+        }
+        table
+    }
+}
+
+/// A multiset of variants of an enumeration, counting the occurrences of each variant.
+///
+/// The counts are stored in an [`EnumTableOf<K, u64>`], so every variant has a count, which
+/// is zero until the variant is added. The accessors locate variants through
+/// [`Enumable::variant_index`], and iteration follows the order of [`Enumable::VARIANTS`].
+///
+/// `add`, `increment` and `merge` overflow as `u64` addition does; the `checked_` and
+/// `saturating_` methods handle overflow explicitly.
+///
+/// # Examples
+///
+/// ```rust
+/// use enum_table::{EnumCounter, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Die {
+///     One,
+///     Two,
+///     Three,
+/// }
+///
+/// let mut rolls: EnumCounter<Die> = [Die::Two, Die::Three, Die::Two].into_iter().collect();
+/// rolls.increment(Die::Two);
+/// rolls.add(Die::One, 2);
+/// assert_eq!(rolls.get(&Die::Two), 3);
+/// assert_eq!(rolls.total(), 6);
+/// assert_eq!(rolls.most_common(2), [(Die::Two, 3), (Die::One, 2)]);
+/// assert_eq!(rolls.frequencies().get(&Die::Two), &0.5);
+/// ```
+pub struct EnumCounter<K: EnumableTable> {
+    counts: EnumTableOf<K, u64>,
+}
+
+impl<K: EnumableTable> EnumCounter<K> {
+    /// Creates a counter where every count is zero.
+    pub fn new() -> Self {
+        Self {
+            counts: EnumTableOf::<K, u64>::new_with_fn(|_| 0),
+        }
+    }
+
+    /// Creates a counter from a table of counts.
+    pub fn from_table(counts: EnumTableOf<K, u64>) -> Self {
+        Self { counts }
+    }
+
+    /// Returns the table of counts.
+    pub fn as_table(&self) -> &EnumTableOf<K, u64> {
+        &self.counts
+    }
+
+    /// Converts the counter into its table of counts.
+    pub fn into_table(self) -> EnumTableOf<K, u64> {
+        self.counts
+    }
+
+    /// Returns the count of a variant.
+    pub fn get(&self, variant: &K) -> u64 {
+        *self.counts.get(variant)
+    }
+
+    /// Sets the count of a variant, returning the old count.
+    pub fn set(&mut self, variant: &K, count: u64) -> u64 {
+        self.counts.set(variant, count)
+    }
+
+    /// Adds one to the count of a variant, returning the new count.
+    pub fn increment(&mut self, variant: K) -> u64 {
+        self.add(variant, 1)
+    }
+
+    /// Adds `n` to the count of a variant, returning the new count.
+    pub fn add(&mut self, variant: K, n: u64) -> u64 {
+        let count = self.counts.get_mut(&variant);
+        *count += n;
+        *count
+    }
+
+    /// Adds `n` to the count of a variant, returning the new count, or `None` and leaving
+    /// the count unchanged on overflow.
+    pub fn checked_add(&mut self, variant: K, n: u64) -> Option<u64> {
+        let count = self.counts.get_mut(&variant);
+        *count = count.checked_add(n)?;
+        Some(*count)
+    }
+
+    /// Adds `n` to the count of a variant, saturating at `u64::MAX`, and returns the new
+    /// count.
+    pub fn saturating_add(&mut self, variant: K, n: u64) -> u64 {
+        let count = self.counts.get_mut(&variant);
+        *count = count.saturating_add(n);
+        *count
+    }
+
+    /// Subtracts `n` from the count of a variant, returning the new count, or `None` and
+    /// leaving the count unchanged if it is less than `n`.
+    pub fn checked_sub(&mut self, variant: K, n: u64) -> Option<u64> {
+        let count = self.counts.get_mut(&variant);
+        *count = count.checked_sub(n)?;
+        Some(*count)
+    }
+
+    /// Subtracts `n` from the count of a variant, saturating at zero, and returns the new
+    /// count.
+    pub fn saturating_sub(&mut self, variant: K, n: u64) -> u64 {
+        let count = self.counts.get_mut(&variant);
+        *count = count.saturating_sub(n);
+        *count
+    }
+
+    /// Returns the sum of all counts, saturating at `u64::MAX`.
+    pub fn total(&self) -> u64 {
+        self.counts
+            .as_slice()
+            .iter()
+            .fold(0, |total, count| total.saturating_add(*count))
+    }
+
+    /// Returns `true` if every count is zero.
+    pub fn is_empty(&self) -> bool {
+        self.counts.as_slice().iter().all(|count| *count == 0)
+    }
+
+    /// Resets every count to zero.
+    pub fn clear(&mut self) {
+        self.counts.as_mut_slice().fill(0);
+    }
+
+    /// Returns up to `n` variants with a nonzero count and their counts, from the most
+    /// common to the least.
+    ///
+    /// Variants with equal counts are in the order of [`Enumable::VARIANTS`].
+    #[cfg(feature = "alloc")]
+    pub fn most_common(&self, n: usize) -> Vec<(K, u64)> {
+        let mut entries: Vec<(K, u64)> = self.iter().filter(|(_, count)| *count != 0).collect();
+        entries.sort_by(|(_, a), (_, b)| b.cmp(a));
+        entries.truncate(n);
+        entries
+    }
+
+    /// Returns the share of the total of each count, which is zero for every variant if the
+    /// total is zero.
+    pub fn frequencies(&self) -> EnumTableOf<K, f64> {
+        let total = self.total();
+        EnumTableOf::<K, f64>::new_with_fn(|variant| {
+            if total == 0 {
+                0.0
+            } else {
+                self.get(variant) as f64 / total as f64
+            }
+        })
+    }
+
+    /// Returns an iterator over the variants and their counts.
+    pub fn iter(&self) -> impl Iterator<Item = (K, u64)> {
+        K::VARIANTS
+            .iter()
+            .copied()
+            .zip(self.counts.as_slice().iter().copied())
+    }
+
+    /// Adds the counts of `other` to the counts of this counter.
+    pub fn merge(&mut self, other: &Self) {
+        for (count, other) in self
+            .counts
+            .as_mut_slice()
+            .iter_mut()
+            .zip(other.counts.as_slice())
+        {
+            *count += other;
+        }
+    }
+
+    /// Returns the sum of the counts of this counter and `other`, or `None` if any count
+    /// overflows.
+    pub fn checked_merge(&self, other: &Self) -> Option<Self> {
+        let mut merged = self.clone();
+        for (count, other) in merged
+            .counts
+            .as_mut_slice()
+            .iter_mut()
+            .zip(other.counts.as_slice())
+        {
+            *count = count.checked_add(*other)?;
+        }
+        Some(merged)
+    }
+
+    /// Adds the counts of `other` to the counts of this counter, saturating at `u64::MAX`.
+    pub fn saturating_merge(&mut self, other: &Self) {
+        for (count, other) in self
+            .counts
+            .as_mut_slice()
+            .iter_mut()
+            .zip(other.counts.as_slice())
+        {
+            *count = count.saturating_add(*other);
+        }
+    }
+}
+
+impl<K: EnumableTable> FromIterator<K> for EnumCounter<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Self::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K: EnumableTable> Extend<K> for EnumCounter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for variant in iter {
+            self.increment(variant);
+        }
+    }
+}
+
+impl<'a, K: EnumableTable> Extend<&'a K> for EnumCounter<K> {
+    fn extend<I: IntoIterator<Item = &'a K>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<K: EnumableTable, const N: usize> From<EnumTable<K, u64, N>> for EnumCounter<K> {
+    fn from(table: EnumTable<K, u64, N>) -> Self {
+        Self::from_table(EnumTableOf::<K, u64>::new_with_fn(|variant| {
+            *table.get(variant)
+        }))
+    }
+}
+
+impl<K: EnumableTable, const N: usize> From<EnumCounter<K>> for EnumTable<K, u64, N> {
+    fn from(counter: EnumCounter<K>) -> Self {
+        EnumTable::new_with_fn(|variant| counter.get(variant))
+    }
+}
+
+impl<K: EnumableTable> core::ops::Add<&EnumCounter<K>> for EnumCounter<K> {
+    type Output = Self;
+
+    fn add(mut self, other: &Self) -> Self {
+        self.merge(other);
+        self
+    }
+}
+
+impl<K: EnumableTable> core::ops::AddAssign<&EnumCounter<K>> for EnumCounter<K> {
+    fn add_assign(&mut self, other: &Self) {
+        self.merge(other);
+    }
+}
+
+impl<K: EnumableTable + core::fmt::Debug> core::fmt::Debug for EnumCounter<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: EnumableTable> Clone for EnumCounter<K> {
+    fn clone(&self) -> Self {
+        Self::from_table(EnumTableOf::<K, u64>::new_with_fn(|variant| {
+            self.get(variant)
+        }))
+    }
+}
+
+impl<K: EnumableTable> PartialEq for EnumCounter<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counts.as_slice() == other.counts.as_slice()
+    }
+}
+
+impl<K: EnumableTable> Eq for EnumCounter<K> {}
+
+impl<K: EnumableTable> core::hash::Hash for EnumCounter<K> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.counts.as_slice().hash(state);
+    }
+}
+
+impl<K: EnumableTable> Default for EnumCounter<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Color {
+        Red = 30,
+        Green = 10,
+        Blue = 20,
+    }
+
+    #[test]
+    fn counting() {
+        let mut counter = EnumCounter::<Color>::new();
+        assert!(counter.is_empty());
+        assert_eq!(counter.increment(Color::Blue), 1);
+        assert_eq!(counter.add(Color::Red, 4), 4);
+        counter.extend([Color::Blue, Color::Green].iter());
+        assert_eq!(counter.get(&Color::Blue), 2);
+        assert_eq!(counter.total(), 7);
+        assert_eq!(format!("{counter:?}"), "{Green: 1, Blue: 2, Red: 4}");
+
+        counter.clear();
+        assert!(counter.is_empty());
+        assert_eq!(counter, EnumCounter::default());
+    }
+
+    #[test]
+    fn overflow() {
+        let mut counter = EnumCounter::<Color>::new();
+        counter.set(&Color::Red, u64::MAX - 1);
+        assert_eq!(counter.checked_add(Color::Red, 2), None);
+        assert_eq!(counter.get(&Color::Red), u64::MAX - 1);
+        assert_eq!(counter.saturating_add(Color::Red, 2), u64::MAX);
+        assert_eq!(counter.checked_sub(Color::Green, 1), None);
+        assert_eq!(counter.saturating_sub(Color::Green, 1), 0);
+        assert_eq!(counter.checked_sub(Color::Red, 1), Some(u64::MAX - 1));
+
+        counter.increment(Color::Blue);
+        assert_eq!(counter.total(), u64::MAX);
+        assert_eq!(counter.checked_merge(&counter), None);
+
+        let mut merged = counter.clone();
+        merged.saturating_merge(&counter);
+        assert_eq!(merged.get(&Color::Red), u64::MAX);
+        assert_eq!(merged.get(&Color::Blue), 2);
+    }
+
+    #[test]
+    fn merge() {
+        let a: EnumCounter<Color> = [Color::Red, Color::Green].into_iter().collect();
+        let b: EnumCounter<Color> = [Color::Red, Color::Blue].into_iter().collect();
+        let mut sum = a.clone() + &b;
+        assert_eq!(
+            sum.iter().collect::<Vec<_>>(),
+            [(Color::Green, 1), (Color::Blue, 1), (Color::Red, 2)]
+        );
+        assert_eq!(a.checked_merge(&b), Some(sum.clone()));
+        sum += &a;
+        assert_eq!(sum.get(&Color::Red), 3);
+    }
+
+    #[test]
+    fn most_common_and_frequencies() {
+        let counter: EnumCounter<Color> = [Color::Red, Color::Blue, Color::Red, Color::Green]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            counter.most_common(usize::MAX),
+            [(Color::Red, 2), (Color::Green, 1), (Color::Blue, 1)]
+        );
+        assert_eq!(counter.most_common(1), [(Color::Red, 2)]);
+        assert_eq!(EnumCounter::<Color>::new().most_common(3), []);
+
+        let frequencies = counter.frequencies();
+        assert_eq!(frequencies.as_slice(), &[0.25, 0.25, 0.5]);
+        assert_eq!(
+            EnumCounter::<Color>::new().frequencies().as_slice(),
+            &[0.0; 3]
+        );
+    }
+
+    #[test]
+    fn histogram() {
+        let table = EnumTable::<Color, u64, { <Color as Enumable>::COUNT }>::histogram(
+            ["red", "blue", "navy", "crimson", "scarlet"],
+            |name| match *name {
+                "red" | "crimson" | "scarlet" => Color::Red,
+                "green" => Color::Green,
+                _ => Color::Blue,
+            },
+        );
+        assert_eq!(table.as_slice(), &[0, 2, 3]);
+
+        let counter = EnumCounter::from(table);
+        assert_eq!(counter.total(), 5);
+        let table: EnumTable<Color, u64, 3> = counter.into();
+        assert_eq!(table.get(&Color::Red), &3);
+    }
+}
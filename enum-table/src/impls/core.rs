@@ -1,7 +1,7 @@
 use core::marker::PhantomData;
 use core::ops::{Index, IndexMut};
 
-use crate::{EnumTable, Enumable};
+use crate::{AnyEnumTable, EnumTable, Enumable};
 
 impl<K: Enumable + core::fmt::Debug, V: core::fmt::Debug, const N: usize> core::fmt::Debug
     for EnumTable<K, V, N>
@@ -84,6 +84,32 @@ impl<K: Enumable, V, const N: usize> AsMut<[V]> for EnumTable<K, V, N> {
     }
 }
 
+impl<K: Enumable, V, const N: usize> AnyEnumTable<K, V> for EnumTable<K, V, N> {
+    fn new_with_fn(f: impl FnMut(&K) -> V) -> Self {
+        EnumTable::new_with_fn(f)
+    }
+
+    fn get(&self, variant: &K) -> &V {
+        EnumTable::get(self, variant)
+    }
+
+    fn get_mut(&mut self, variant: &K) -> &mut V {
+        EnumTable::get_mut(self, variant)
+    }
+
+    fn set(&mut self, variant: &K, value: V) -> V {
+        EnumTable::set(self, variant, value)
+    }
+
+    fn as_slice(&self) -> &[V] {
+        EnumTable::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [V] {
+        EnumTable::as_mut_slice(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::hash::{Hash, Hasher};
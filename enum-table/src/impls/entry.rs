@@ -0,0 +1,283 @@
+use crate::{EnumTable, Enumable};
+
+impl<K: Enumable, V, const N: usize> EnumTable<K, Option<V>, N> {
+    /// Returns the entry of the given enumeration variant, for in-place manipulation of its
+    /// value, as with [`std::collections::hash_map::Entry`].
+    ///
+    /// The slot of the variant is occupied if its value is `Some`.
+    /// Uses O(1) lookup via [`Enumable::variant_index`].
+    ///
+    /// # Arguments
+    ///
+    /// * `variant` - A reference to an enumeration variant.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use enum_table::{EnumTable, Enumable};
+    ///
+    /// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+    /// enum Color {
+    ///     Red,
+    ///     Green,
+    /// }
+    ///
+    /// let mut table = EnumTable::<Color, Option<u32>, { Color::COUNT }>::new_fill_with_none();
+    /// for color in [Color::Red, Color::Green, Color::Red] {
+    ///     table.entry(&color).and_modify(|count| *count += 1).or_insert(1);
+    /// }
+    /// assert_eq!(table.get(&Color::Red), &Some(2));
+    /// assert_eq!(table.get(&Color::Green), &Some(1));
+    /// ```
+    pub fn entry(&mut self, variant: &K) -> Entry<'_, K, V> {
+        let slot = self.slot_mut(variant);
+        if slot.is_some() {
+            Entry::Occupied(OccupiedEntry {
+                variant: *variant,
+                slot,
+            })
+        } else {
+            Entry::Vacant(VacantEntry {
+                variant: *variant,
+                slot,
+            })
+        }
+    }
+}
+
+/// The entry of a variant in an `EnumTable<K, Option<V>, N>`, which is either occupied or
+/// vacant.
+///
+/// Returned by [`EnumTable::entry`].
+pub enum Entry<'a, K, V> {
+    /// The value of the variant is `Some`.
+    Occupied(OccupiedEntry<'a, K, V>),
+    /// The value of the variant is `None`.
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    /// Returns the variant of this entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default),
+        }
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable
+    /// reference to the value.
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    /// Inserts the result of `default`, which is given the variant, if the entry is vacant,
+    /// and returns a mutable reference to the value.
+    pub fn or_insert_with_key(self, default: impl FnOnce(&K) -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let value = default(entry.key());
+                entry.insert(value)
+            }
+        }
+    }
+
+    /// Calls `f` with the value if the entry is occupied, and returns the entry.
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V: Default> Entry<'a, K, V> {
+    /// Inserts the default value if the entry is vacant, and returns a mutable reference to
+    /// the value.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+/// An occupied [`Entry`], whose value is `Some`.
+pub struct OccupiedEntry<'a, K, V> {
+    variant: K,
+    /// Always `Some`.
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    /// Returns the variant of this entry.
+    pub fn key(&self) -> &K {
+        &self.variant
+    }
+
+    /// Returns a reference to the value.
+    pub fn get(&self) -> &V {
+        let Some(value) = &*self.slot else {
+            unreachable!("the slot of an occupied entry is `Some`")
+        };
+        value
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// Use [`Self::into_mut`] for a reference that outlives the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        let Some(value) = self.slot else {
+            unreachable!("the slot of an occupied entry is `Some`")
+        };
+        value
+    }
+
+    /// Converts the entry into a mutable reference to the value.
+    pub fn into_mut(self) -> &'a mut V {
+        let Some(value) = self.slot else {
+            unreachable!("the slot of an occupied entry is `Some`")
+        };
+        value
+    }
+
+    /// Replaces the value, and returns the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Takes the value out of the table, leaving `None` in its place.
+    pub fn remove(self) -> V {
+        let Some(value) = self.slot.take() else {
+            unreachable!("the slot of an occupied entry is `Some`")
+        };
+        value
+    }
+}
+
+/// A vacant [`Entry`], whose value is `None`.
+pub struct VacantEntry<'a, K, V> {
+    variant: K,
+    slot: &'a mut Option<V>,
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    /// Returns the variant of this entry.
+    pub fn key(&self) -> &K {
+        &self.variant
+    }
+
+    /// Sets the value of the entry, and returns a mutable reference to it.
+    pub fn insert(self, value: V) -> &'a mut V {
+        self.slot.insert(value)
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for Entry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Entry::Occupied(entry) => f.debug_tuple("Entry").field(entry).finish(),
+            Entry::Vacant(entry) => f.debug_tuple("Entry").field(entry).finish(),
+        }
+    }
+}
+
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for OccupiedEntry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("OccupiedEntry")
+            .field("key", self.key())
+            .field("value", self.get())
+            .finish()
+    }
+}
+
+impl<K: core::fmt::Debug, V> core::fmt::Debug for VacantEntry<'_, K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_tuple("VacantEntry").field(self.key()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    enum Color {
+        Red = 33,
+        Green = 11,
+        Blue = 222,
+    }
+
+    type Table = EnumTable<Color, Option<String>, { Color::COUNT }>;
+
+    #[test]
+    fn or_insert() {
+        let mut table = Table::new_fill_with_none();
+        table
+            .entry(&Color::Red)
+            .or_insert("red".to_owned())
+            .push('!');
+        table
+            .entry(&Color::Red)
+            .or_insert("ignored".to_owned())
+            .push('?');
+        assert_eq!(table.get(&Color::Red).as_deref(), Some("red!?"));
+
+        let value = table
+            .entry(&Color::Blue)
+            .or_insert_with_key(|color| format!("{color:?}"));
+        assert_eq!(value, "Blue");
+        assert_eq!(table.entry(&Color::Green).or_default(), "");
+        assert_eq!(
+            table.entry(&Color::Green).or_insert_with(|| "x".to_owned()),
+            ""
+        );
+    }
+
+    #[test]
+    fn and_modify() {
+        let mut table = EnumTable::<Color, Option<u32>, { Color::COUNT }>::new_fill_with_none();
+        for _ in 0..3 {
+            table
+                .entry(&Color::Green)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+        assert_eq!(table.get(&Color::Green), &Some(3));
+        assert_eq!(table.get(&Color::Red), &None);
+    }
+
+    #[test]
+    fn occupied_and_vacant() {
+        let mut table = Table::new_fill_with_none();
+        match table.entry(&Color::Red) {
+            Entry::Vacant(entry) => {
+                assert_eq!(entry.key(), &Color::Red);
+                entry.insert("red".to_owned());
+            }
+            Entry::Occupied(_) => unreachable!(),
+        }
+
+        let Entry::Occupied(mut entry) = table.entry(&Color::Red) else {
+            unreachable!()
+        };
+        assert_eq!(entry.get(), "red");
+        assert_eq!(entry.insert("RED".to_owned()), "red");
+        assert_eq!(
+            format!("{entry:?}"),
+            r#"OccupiedEntry { key: Red, value: "RED" }"#
+        );
+        assert_eq!(entry.remove(), "RED");
+        assert_eq!(table.get(&Color::Red), &None);
+        assert_eq!(table.entry(&Color::Red).key(), &Color::Red);
+    }
+}
@@ -0,0 +1,537 @@
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+
+use crate::composite::{CompositeKey, CompositeTable, Concat, Rows};
+use crate::intrinsics::{VariantStorage, stored_variants, variant_storage};
+use crate::layout::{Arm, Field, Layout, payload_offset};
+use crate::set::Bits;
+use crate::{EnumTable, EnumTableOf, Enumable, EnumableTable};
+
+impl Enumable for bool {
+    const VARIANTS: &'static [Self] = &[false, true];
+
+    fn variant_index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl EnumableTable for bool {
+    type Table<V> = EnumTable<bool, V, 2>;
+    type Bits = [u64; 1];
+    type BitsArray<B: Bits> = [B; 2];
+}
+
+impl Enumable for Ordering {
+    const VARIANTS: &'static [Self] = &[Ordering::Less, Ordering::Equal, Ordering::Greater];
+    const LAYOUT: Layout = Layout::signed::<Self>();
+
+    fn variant_index(&self) -> usize {
+        (*self as i8 + 1) as usize
+    }
+}
+
+impl EnumableTable for Ordering {
+    type Table<V> = EnumTable<Ordering, V, 3>;
+    type Bits = [u64; 1];
+    type BitsArray<B: Bits> = [B; 3];
+}
+
+/// Storage for `VARIANTS` and layout descriptions of the generic implementations below.
+///
+/// These need `'static` constants that depend on the generic parameters, which the
+/// `impl Enumable` blocks cannot declare themselves.
+struct Parts<T>(PhantomData<T>);
+
+/// `None`, then `Some` of each variant of `K`.
+impl<K: Enumable> Enumable for Option<K> {
+    const VARIANTS: &'static [Self] =
+        unsafe { stored_variants(Parts::<Self>::STORAGE, Parts::<Self>::COUNT) };
+    const COUNT: usize = Parts::<Self>::COUNT;
+    // SAFETY: the arms describe `None` and `Some` in the order of `VARIANTS`.
+    const LAYOUT: Layout = unsafe { Layout::variants::<Self>(Parts::<Self>::ARMS) };
+
+    fn variant_index(&self) -> usize {
+        match self {
+            None => 0,
+            Some(value) => 1 + value.variant_index(),
+        }
+    }
+}
+
+impl<K: Enumable> Parts<Option<K>> {
+    const COUNT: usize = K::COUNT + 1;
+    const STORAGE: &'static [MaybeUninit<u8>] =
+        variant_storage!(Option<K>, Self::COUNT, option_variants::<K>);
+    const ARMS: &'static [Arm] = &[
+        Arm {
+            base: 0,
+            count: 1,
+            offset: 0,
+            size: 0,
+            layout: None,
+        },
+        Arm {
+            base: 1,
+            count: K::COUNT,
+            offset: match <Option<K>>::VARIANTS {
+                // SAFETY: `value` is a field of `variant`.
+                [_, variant @ Some(value), ..] => unsafe { payload_offset(variant, value) },
+                _ => 0,
+            },
+            size: core::mem::size_of::<K>(),
+            layout: Some(&K::LAYOUT),
+        },
+    ];
+}
+
+impl<K: EnumableTable> EnumableTable for Option<K> {
+    type Table<V> = CompositeTable<Self, V>;
+    type Bits = Concat<[u64; 1], K::Bits>;
+    type BitsArray<B: Bits> = Concat<[B; 1], K::BitsArray<B>>;
+}
+
+impl<K: EnumableTable> crate::composite::private::Sealed for Option<K> {}
+
+impl<K: EnumableTable> CompositeKey for Option<K> {
+    type Storage<V> = Concat<[V; 1], EnumTableOf<K, V>>;
+}
+
+const fn option_variants<K: Enumable, const B: usize>() -> VariantStorage<B> {
+    let mut storage = VariantStorage::new();
+    storage.write(0, None::<K>);
+    let mut i = 0;
+    while i < K::COUNT {
+        storage.write(1 + i, Some(K::VARIANTS[i]));
+        i += 1;
+    }
+    storage
+}
+
+/// `Ok` of each variant of `T`, then `Err` of each variant of `E`.
+impl<T: Enumable, E: Enumable> Enumable for Result<T, E> {
+    const VARIANTS: &'static [Self] =
+        unsafe { stored_variants(Parts::<Self>::STORAGE, Parts::<Self>::COUNT) };
+    const COUNT: usize = Parts::<Self>::COUNT;
+    // SAFETY: the arms describe `Ok` and `Err` in the order of `VARIANTS`.
+    const LAYOUT: Layout = unsafe { Layout::variants::<Self>(Parts::<Self>::ARMS) };
+
+    fn variant_index(&self) -> usize {
+        match self {
+            Ok(value) => value.variant_index(),
+            Err(error) => T::COUNT + error.variant_index(),
+        }
+    }
+}
+
+impl<T: Enumable, E: Enumable> Parts<Result<T, E>> {
+    const COUNT: usize = T::COUNT + E::COUNT;
+    const STORAGE: &'static [MaybeUninit<u8>] =
+        variant_storage!(Result<T, E>, Self::COUNT, result_variants::<T, E>);
+    const ARMS: &'static [Arm] = &[
+        Arm {
+            base: 0,
+            count: T::COUNT,
+            offset: match <Result<T, E>>::VARIANTS {
+                // SAFETY: `value` is a field of `variant`.
+                [variant @ Ok(value), ..] => unsafe { payload_offset(variant, value) },
+                _ => 0,
+            },
+            size: core::mem::size_of::<T>(),
+            layout: Some(&T::LAYOUT),
+        },
+        Arm {
+            base: T::COUNT,
+            count: E::COUNT,
+            offset: match <Result<T, E>>::VARIANTS.split_at(T::COUNT).1 {
+                // SAFETY: `error` is a field of `variant`.
+                [variant @ Err(error), ..] => unsafe { payload_offset(variant, error) },
+                _ => 0,
+            },
+            size: core::mem::size_of::<E>(),
+            layout: Some(&E::LAYOUT),
+        },
+    ];
+}
+
+impl<T: EnumableTable, E: EnumableTable> EnumableTable for Result<T, E> {
+    type Table<V> = CompositeTable<Self, V>;
+    type Bits = Concat<T::Bits, E::Bits>;
+    type BitsArray<B: Bits> = Concat<T::BitsArray<B>, E::BitsArray<B>>;
+}
+
+impl<T: EnumableTable, E: EnumableTable> crate::composite::private::Sealed for Result<T, E> {}
+
+impl<T: EnumableTable, E: EnumableTable> CompositeKey for Result<T, E> {
+    type Storage<V> = Concat<EnumTableOf<T, V>, EnumTableOf<E, V>>;
+}
+
+const fn result_variants<T: Enumable, E: Enumable, const B: usize>() -> VariantStorage<B> {
+    let mut storage = VariantStorage::new();
+    let mut i = 0;
+    while i < T::COUNT {
+        storage.write(i, Ok::<T, E>(T::VARIANTS[i]));
+        i += 1;
+    }
+    let mut i = 0;
+    while i < E::COUNT {
+        storage.write(T::COUNT + i, Err::<T, E>(E::VARIANTS[i]));
+        i += 1;
+    }
+    storage
+}
+
+/// The cartesian product of the elements, with the first element most significant.
+impl<A: Enumable, B: Enumable> Enumable for (A, B) {
+    const VARIANTS: &'static [Self] =
+        unsafe { stored_variants(Parts::<Self>::STORAGE, Parts::<Self>::COUNT) };
+    const COUNT: usize = Parts::<Self>::COUNT;
+    // SAFETY: the fields describe both elements in order.
+    const LAYOUT: Layout = unsafe { Layout::product(Parts::<Self>::FIELDS) };
+
+    fn variant_index(&self) -> usize {
+        self.0.variant_index() * B::COUNT + self.1.variant_index()
+    }
+}
+
+impl<A: Enumable, B: Enumable> Parts<(A, B)> {
+    const COUNT: usize = A::COUNT * B::COUNT;
+    const STORAGE: &'static [MaybeUninit<u8>] =
+        variant_storage!((A, B), Self::COUNT, pair_variants::<A, B>);
+    const FIELDS: &'static [Field] = &[
+        Field {
+            offset: core::mem::offset_of!((A, B), 0),
+            count: A::COUNT,
+            size: core::mem::size_of::<A>(),
+            layout: &A::LAYOUT,
+        },
+        Field {
+            offset: core::mem::offset_of!((A, B), 1),
+            count: B::COUNT,
+            size: core::mem::size_of::<B>(),
+            layout: &B::LAYOUT,
+        },
+    ];
+}
+
+impl<A: EnumableTable, B: EnumableTable> EnumableTable for (A, B) {
+    type Table<V> = CompositeTable<Self, V>;
+    type Bits = A::BitsArray<B::Bits>;
+    type BitsArray<W: Bits> = A::BitsArray<B::BitsArray<W>>;
+}
+
+impl<A: EnumableTable, B: EnumableTable> crate::composite::private::Sealed for (A, B) {}
+
+/// A row of `B` for each variant of `A`.
+impl<A: EnumableTable, B: EnumableTable> CompositeKey for (A, B) {
+    type Storage<V> = Rows<EnumTableOf<A, EnumTableOf<B, V>>, EnumTableOf<B, V>>;
+}
+
+const fn pair_variants<A: Enumable, B: Enumable, const S: usize>() -> VariantStorage<S> {
+    let mut storage = VariantStorage::new();
+    let mut i = 0;
+    while i < A::COUNT * B::COUNT {
+        storage.write(i, (A::VARIANTS[i / B::COUNT], B::VARIANTS[i % B::COUNT]));
+        i += 1;
+    }
+    storage
+}
+
+/// The cartesian product of the elements, with the first element most significant.
+impl<A: Enumable, B: Enumable, C: Enumable> Enumable for (A, B, C) {
+    const VARIANTS: &'static [Self] =
+        unsafe { stored_variants(Parts::<Self>::STORAGE, Parts::<Self>::COUNT) };
+    const COUNT: usize = Parts::<Self>::COUNT;
+    // SAFETY: the fields describe all three elements in order.
+    const LAYOUT: Layout = unsafe { Layout::product(Parts::<Self>::FIELDS) };
+
+    fn variant_index(&self) -> usize {
+        (self.0.variant_index() * B::COUNT + self.1.variant_index()) * C::COUNT
+            + self.2.variant_index()
+    }
+}
+
+impl<A: Enumable, B: Enumable, C: Enumable> Parts<(A, B, C)> {
+    const COUNT: usize = A::COUNT * B::COUNT * C::COUNT;
+    const STORAGE: &'static [MaybeUninit<u8>] =
+        variant_storage!((A, B, C), Self::COUNT, triple_variants::<A, B, C>);
+    const FIELDS: &'static [Field] = &[
+        Field {
+            offset: core::mem::offset_of!((A, B, C), 0),
+            count: A::COUNT,
+            size: core::mem::size_of::<A>(),
+            layout: &A::LAYOUT,
+        },
+        Field {
+            offset: core::mem::offset_of!((A, B, C), 1),
+            count: B::COUNT,
+            size: core::mem::size_of::<B>(),
+            layout: &B::LAYOUT,
+        },
+        Field {
+            offset: core::mem::offset_of!((A, B, C), 2),
+            count: C::COUNT,
+            size: core::mem::size_of::<C>(),
+            layout: &C::LAYOUT,
+        },
+    ];
+}
+
+impl<A: EnumableTable, B: EnumableTable, C: EnumableTable> EnumableTable for (A, B, C) {
+    type Table<V> = CompositeTable<Self, V>;
+    type Bits = A::BitsArray<<(B, C) as EnumableTable>::Bits>;
+    type BitsArray<W: Bits> = A::BitsArray<<(B, C) as EnumableTable>::BitsArray<W>>;
+}
+
+impl<A: EnumableTable, B: EnumableTable, C: EnumableTable> crate::composite::private::Sealed
+    for (A, B, C)
+{
+}
+
+/// A row of `(B, C)` for each variant of `A`.
+impl<A: EnumableTable, B: EnumableTable, C: EnumableTable> CompositeKey for (A, B, C) {
+    type Storage<V> = Rows<EnumTableOf<A, EnumTableOf<(B, C), V>>, EnumTableOf<(B, C), V>>;
+}
+
+const fn triple_variants<A: Enumable, B: Enumable, C: Enumable, const S: usize>()
+-> VariantStorage<S> {
+    let mut storage = VariantStorage::new();
+    let mut i = 0;
+    while i < A::COUNT * B::COUNT * C::COUNT {
+        let rest = i / C::COUNT;
+        storage.write(
+            i,
+            (
+                A::VARIANTS[rest / B::COUNT],
+                B::VARIANTS[rest % B::COUNT],
+                C::VARIANTS[i % C::COUNT],
+            ),
+        );
+        i += 1;
+    }
+    storage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{EnumTable, et};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    #[repr(u16)]
+    enum Size {
+        Small = 300,
+        Large = 1,
+    }
+
+    fn assert_indices<K: Enumable + core::fmt::Debug + PartialEq>() {
+        assert_eq!(K::VARIANTS.len(), K::COUNT);
+        for (i, variant) in K::VARIANTS.iter().enumerate() {
+            assert_eq!(variant.variant_index(), i, "{variant:?}");
+            assert_eq!(
+                crate::intrinsics::const_variant_index(variant),
+                i,
+                "{variant:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bool_variants() {
+        assert_eq!(bool::VARIANTS, &[false, true]);
+        assert_indices::<bool>();
+    }
+
+    #[test]
+    fn ordering_variants() {
+        assert_eq!(
+            Ordering::VARIANTS,
+            &[Ordering::Less, Ordering::Equal, Ordering::Greater]
+        );
+        assert_indices::<Ordering>();
+    }
+
+    #[test]
+    fn impl_enumable_macro() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(u16)]
+        enum Opcode {
+            Write = 700,
+            Read = 3,
+            Sync = 40,
+        }
+        crate::impl_enumable!(Opcode { Write, Read, Sync });
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Implicit {
+            Zero,
+            One,
+        }
+        crate::impl_enumable!(Implicit { One, Zero });
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(i8)]
+        enum Temp {
+            Hot = 1,
+            Cold = -1,
+            Mild = 0,
+        }
+        crate::impl_enumable!(Temp { Hot, Cold, Mild });
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        enum Offset {
+            Back = -300,
+            Here = 0,
+            Ahead = 5000,
+        }
+        crate::impl_enumable!(Offset { Ahead, Here, Back });
+
+        assert_eq!(
+            Opcode::VARIANTS,
+            &[Opcode::Read, Opcode::Sync, Opcode::Write]
+        );
+        assert_indices::<Opcode>();
+        assert_eq!(Implicit::VARIANTS, &[Implicit::Zero, Implicit::One]);
+        assert_indices::<Implicit>();
+        assert_eq!(Temp::VARIANTS, &[Temp::Cold, Temp::Mild, Temp::Hot]);
+        assert_indices::<Temp>();
+        assert_eq!(
+            Offset::VARIANTS,
+            &[Offset::Back, Offset::Here, Offset::Ahead]
+        );
+        assert_indices::<Offset>();
+
+        const TABLE: crate::EnumTable<Opcode, u8, 3> =
+            crate::et!(Opcode, u8, |opcode| (*opcode as u16 % 256) as u8);
+        assert_eq!(*TABLE.get_const(&Opcode::Write), 188);
+
+        const TEMPS: crate::EnumTable<Temp, i8, 3> = crate::et!(Temp, i8, |temp| *temp as i8);
+        assert_eq!(*TEMPS.get_const(&Temp::Cold), -1);
+        assert_eq!(TEMPS.as_slice(), &[-1, 0, 1]);
+    }
+
+    #[test]
+    fn option_variants() {
+        assert_eq!(
+            <Option<Color>>::VARIANTS,
+            &[
+                None,
+                Some(Color::Red),
+                Some(Color::Green),
+                Some(Color::Blue)
+            ]
+        );
+        assert_indices::<Option<Color>>();
+        assert_indices::<Option<Size>>();
+        assert_indices::<Option<bool>>();
+        assert_indices::<Option<Option<Color>>>();
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    struct Cell {
+        color: Color,
+        size: Size,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Paint {
+        Fill(Color),
+        Resize(Size),
+        Clear,
+    }
+
+    #[test]
+    fn option_payload_variants() {
+        assert_eq!(<Option<(Color, Size)>>::COUNT, 7);
+        assert_eq!(
+            <Option<(Color, Size)>>::VARIANTS[1],
+            Some((Color::Red, Size::Large))
+        );
+        assert_indices::<Option<(Color, Size)>>();
+        assert_indices::<Option<(bool, Color)>>();
+        assert_indices::<Option<Cell>>();
+        assert_indices::<Option<Paint>>();
+        assert_indices::<Result<Cell, Paint>>();
+    }
+
+    #[test]
+    fn result_variants() {
+        assert_eq!(
+            <Result<Size, bool>>::VARIANTS,
+            &[Ok(Size::Large), Ok(Size::Small), Err(false), Err(true)]
+        );
+        assert_indices::<Result<Size, bool>>();
+        assert_indices::<Result<Color, Option<Size>>>();
+    }
+
+    #[test]
+    fn tuple_variants() {
+        assert_eq!(<(Color, bool)>::COUNT, 6);
+        assert_eq!(<(Color, bool)>::VARIANTS[3], (Color::Green, true));
+        assert_indices::<(Color, bool)>();
+        assert_indices::<(Size, Color)>();
+
+        assert_eq!(<(bool, Color, Size)>::COUNT, 12);
+        assert_eq!(
+            <(bool, Color, Size)>::VARIANTS[7],
+            (true, Color::Red, Size::Small)
+        );
+        assert_indices::<(bool, Color, Size)>();
+        assert_indices::<(Ordering, Option<Color>, bool)>();
+    }
+
+    #[test]
+    fn get_const() {
+        const TABLE: EnumTable<Option<Color>, u8, { <Option<Color>>::COUNT }> =
+            et!(Option<Color>, u8, |color| match color {
+                None => 0,
+                Some(color) => *color as u8 + 1,
+            });
+        const BLUE: &u8 = TABLE.get_const(&Some(Color::Blue));
+        const NONE: &u8 = TABLE.get_const(&None);
+        assert_eq!(*BLUE, 3);
+        assert_eq!(*NONE, 0);
+
+        const PAIRS: EnumTable<(Size, Ordering), i8, { <(Size, Ordering)>::COUNT }> =
+            et!((Size, Ordering), i8, |pair| pair.1 as i8);
+        const LESS: &i8 = PAIRS.get_const(&(Size::Small, Ordering::Less));
+        assert_eq!(*LESS, -1);
+
+        const CELLS: EnumTable<Option<Cell>, u8, { <Option<Cell>>::COUNT }> =
+            et!(Option<Cell>, u8, |cell| match cell {
+                None => 0,
+                Some(cell) => cell.color as u8 * 10 + cell.size as u8 % 2,
+            });
+        const GREEN: &u8 = CELLS.get_const(&Some(Cell {
+            color: Color::Green,
+            size: Size::Large,
+        }));
+        assert_eq!(*GREEN, 11);
+        assert_eq!(*CELLS.get_const(&None), 0);
+
+        const PAINTS: EnumTable<Option<Paint>, u8, { <Option<Paint>>::COUNT }> =
+            et!(Option<Paint>, u8, |paint| match paint {
+                None => 0,
+                Some(Paint::Fill(color)) => *color as u8 + 1,
+                Some(Paint::Resize(_)) => 10,
+                Some(Paint::Clear) => 20,
+            });
+        const CLEAR: &u8 = PAINTS.get_const(&Some(Paint::Clear));
+        assert_eq!(*CLEAR, 20);
+        assert_eq!(*PAINTS.get_const(&Some(Paint::Fill(Color::Blue))), 3);
+        assert_eq!(*PAINTS.get_const(&Some(Paint::Resize(Size::Small))), 10);
+        assert_eq!(*PAINTS.get_const(&None), 0);
+
+        let paired = et!(Option<(Color, Size)>, usize, |pair| pair.variant_index());
+        for pair in <Option<(Color, Size)>>::VARIANTS {
+            assert_eq!(*paired.get_const(pair), pair.variant_index());
+        }
+    }
+}
@@ -25,6 +25,41 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
         self.into_iter()
     }
+
+    /// Returns an iterator over references to the key-value pairs of the variants in
+    /// `group`, in its order.
+    ///
+    /// `group` is typically a constant generated by `#[enumable(group = "...")]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use enum_table::{EnumTable, Enumable};
+    ///
+    /// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+    /// enum Key {
+    ///     #[enumable(group = "movement")]
+    ///     W,
+    ///     #[enumable(group = "movement")]
+    ///     S,
+    ///     Escape,
+    /// }
+    ///
+    /// let table = EnumTable::<Key, bool, { Key::COUNT }>::new_with_fn(|key| *key != Key::S);
+    /// let pressed = table.iter_group(Key::MOVEMENT).filter(|(_, pressed)| **pressed);
+    /// assert_eq!(pressed.map(|(key, _)| *key).collect::<Vec<_>>(), [Key::W]);
+    /// ```
+    pub fn iter_group<'a>(&'a self, group: &'a [K]) -> impl Iterator<Item = (&'a K, &'a V)> {
+        group.iter().map(|variant| (variant, self.get(variant)))
+    }
+
+    /// Returns an iterator over references to the values of the variants in `group`, in
+    /// its order.
+    ///
+    /// See [`Self::iter_group`].
+    pub fn values_in<'a>(&'a self, group: &'a [K]) -> impl Iterator<Item = &'a V> {
+        group.iter().map(|variant| self.get(variant))
+    }
 }
 
 impl<K: Enumable, V, const N: usize> IntoIterator for EnumTable<K, V, N> {
@@ -155,4 +190,42 @@ mod tests {
         assert_eq!(table.get(&Color::Red), &"New Red");
         assert_eq!(table.get(&Color::Green), &"Green");
     }
+
+    #[test]
+    fn groups() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+        #[repr(u8)]
+        enum Key {
+            #[enumable(group = "movement", group = "letters")]
+            W = 3,
+            #[enumable(group = "letters")]
+            Q = 2,
+            #[enumable(group = "movement")]
+            Up = 1,
+            Escape = 0,
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+        #[enumable(order = "declaration")]
+        enum Shape {
+            #[enumable(group = "round-ish")]
+            Oval = 1,
+            Square = 2,
+            #[enumable(group = "round-ish")]
+            Circle = 0,
+        }
+
+        // Groups follow the order of `VARIANTS`.
+        assert_eq!(Key::MOVEMENT, &[Key::Up, Key::W]);
+        assert_eq!(Key::LETTERS, &[Key::Q, Key::W]);
+        assert_eq!(Shape::ROUND_ISH, &[Shape::Oval, Shape::Circle]);
+
+        let table = EnumTable::<Key, u8, { Key::COUNT }>::new_with_fn(|key| *key as u8 * 10);
+        assert_eq!(
+            table.iter_group(Key::MOVEMENT).collect::<Vec<_>>(),
+            [(&Key::Up, &10), (&Key::W, &30)]
+        );
+        assert_eq!(table.values_in(Key::LETTERS).sum::<u8>(), 50);
+        assert_eq!(table.values_in(&[]).count(), 0);
+    }
 }
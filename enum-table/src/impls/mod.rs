@@ -2,6 +2,11 @@ mod iter;
 
 mod core;
 
+mod enumable;
+
+mod entry;
+pub use entry::*;
+
 #[cfg(feature = "std")]
 mod map;
 
@@ -14,3 +19,6 @@ pub use vec::*;
 mod serde;
 #[cfg(all(feature = "serde", not(feature = "alloc")))]
 compile_error!("`serde` feature requires `alloc` feature");
+
+mod names;
+pub use names::*;
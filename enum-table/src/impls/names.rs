@@ -0,0 +1,153 @@
+use crate::{EnumTable, EnumableNames};
+
+/// Error type for parsing a variant from its name.
+///
+/// Returned by the [`core::str::FromStr`] implementations of `#[derive(Enumable)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseNameError {
+    expected: &'static [&'static str],
+}
+
+impl ParseNameError {
+    /// Creates an error for a name that matches no variant of `K`.
+    pub fn new<K: EnumableNames>() -> Self {
+        Self { expected: K::NAMES }
+    }
+
+    /// Returns the valid names, in the order of [`crate::Enumable::VARIANTS`].
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+}
+
+impl core::fmt::Display for ParseNameError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unknown variant name: expected one of ")?;
+        for (i, name) in self.expected.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "`{name}`")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseNameError {}
+
+impl<K: EnumableNames, V, const N: usize> EnumTable<K, V, N> {
+    /// Returns a reference to the value associated with the variant named `name`, or
+    /// `None` if no variant has that name.
+    ///
+    /// This is meant for keys read from text, such as configuration files.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of an enumeration variant, as in [`EnumableNames::from_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use enum_table::{EnumTable, Enumable};
+    ///
+    /// #[derive(Enumable, Copy, Clone)]
+    /// #[enumable(rename_all = "lowercase", ascii_case_insensitive)]
+    /// enum Level {
+    ///     Low,
+    ///     High,
+    /// }
+    ///
+    /// let table = EnumTable::<Level, u8, { Level::COUNT }>::new_with_fn(|level| match level {
+    ///     Level::Low => 1,
+    ///     Level::High => 9,
+    /// });
+    /// assert_eq!(table.get_by_name("HIGH"), Some(&9));
+    /// assert_eq!(table.get_by_name("medium"), None);
+    /// ```
+    pub fn get_by_name(&self, name: &str) -> Option<&V> {
+        K::from_name(name).map(|variant| self.get(&variant))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EnumTable, Enumable, EnumableNames, ParseNameError};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(rename_all = "snake_case")]
+    #[repr(u8)]
+    enum Format {
+        #[enumable(alias = "yml")]
+        Yaml = 2,
+        Json = 0,
+        #[enumable(alias = "tml")]
+        PlainToml = 1,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(ascii_case_insensitive)]
+    enum Switch {
+        On,
+        #[enumable(alias = "disabled")]
+        Off,
+    }
+
+    /// A manual implementation relying on the default `from_name`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Manual {
+        First,
+        Second,
+    }
+
+    impl Enumable for Manual {
+        const VARIANTS: &'static [Self] = &[Manual::First, Manual::Second];
+    }
+
+    impl EnumableNames for Manual {
+        const NAMES: &'static [&'static str] = &["first", "second"];
+        const DOCS: &'static [&'static str] = &["", ""];
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Format::from_name("json"), Some(Format::Json));
+        assert_eq!(Format::from_name("plain_toml"), Some(Format::PlainToml));
+        assert_eq!(Format::from_name("tml"), Some(Format::PlainToml));
+        assert_eq!(Format::from_name("yml"), Some(Format::Yaml));
+        assert_eq!(Format::from_name("Json"), None);
+        assert_eq!(Format::from_name("PlainToml"), None);
+        assert_eq!(Format::from_name(""), None);
+
+        assert_eq!(Manual::from_name("second"), Some(Manual::Second));
+        assert_eq!(Manual::from_name("third"), None);
+    }
+
+    #[test]
+    fn from_name_ignore_case() {
+        assert_eq!(Switch::from_name("on"), Some(Switch::On));
+        assert_eq!(Switch::from_name("OFF"), Some(Switch::Off));
+        assert_eq!(Switch::from_name("Disabled"), Some(Switch::Off));
+        assert_eq!(Switch::from_name("onn"), None);
+        // The names themselves keep their case.
+        assert_eq!(Switch::NAMES, &["On", "Off"]);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("yaml".parse::<Format>(), Ok(Format::Yaml));
+        let err = "xml".parse::<Format>().unwrap_err();
+        assert_eq!(err, ParseNameError::new::<Format>());
+        assert_eq!(err.expected(), &["json", "plain_toml", "yaml"]);
+        assert_eq!(
+            err.to_string(),
+            "Unknown variant name: expected one of `json`, `plain_toml`, `yaml`"
+        );
+    }
+
+    #[test]
+    fn get_by_name() {
+        let table = EnumTable::<Format, u8, { Format::COUNT }>::new_with_fn(|format| *format as u8);
+        assert_eq!(table.get_by_name("yml"), Some(&2));
+        assert_eq!(table.get_by_name("toml"), None);
+    }
+}
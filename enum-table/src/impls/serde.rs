@@ -1,4 +1,8 @@
-use crate::{EnumTable, Enumable};
+use alloc::format;
+use alloc::vec::Vec;
+
+use crate::composite::{CompositeKey, CompositeTable};
+use crate::{EnumMap, EnumMatrix, EnumSet, EnumTable, EnumTableOf, Enumable, EnumableTable};
 
 impl<K, V, const N: usize> serde::Serialize for EnumTable<K, V, N>
 where
@@ -86,6 +90,229 @@ where
     }
 }
 
+impl<K, V> serde::Serialize for CompositeTable<K, V>
+where
+    K: CompositeKey + serde::Serialize,
+    V: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use crate::AnyEnumTable;
+        use serde::ser::SerializeMap;
+        let mut map = serializer.serialize_map(Some(K::COUNT))?;
+        for (key, value) in self.iter() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, K, V> serde::Deserialize<'de> for CompositeTable<K, V>
+where
+    K: CompositeKey + serde::Deserialize<'de> + core::fmt::Debug,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use core::marker::PhantomData;
+        use serde::de::{MapAccess, Visitor};
+
+        struct CompositeTableVisitor<K, V> {
+            _phantom: PhantomData<(K, V)>,
+        }
+
+        impl<'de, K, V> Visitor<'de> for CompositeTableVisitor<K, V>
+        where
+            K: CompositeKey + serde::Deserialize<'de> + core::fmt::Debug,
+            V: serde::Deserialize<'de>,
+        {
+            type Value = CompositeTable<K, V>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a map with all enum variants as keys")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut slots: Vec<Option<V>> = (0..K::COUNT).map(|_| None).collect();
+                let mut count = 0;
+
+                while let Some((key, value)) = map.next_entry::<K, V>()? {
+                    slots[key.variant_index()] = Some(value);
+                    count += 1;
+                }
+
+                if count != K::COUNT {
+                    return Err(serde::de::Error::invalid_length(
+                        count,
+                        &format!("expected {} entries", K::COUNT).as_str(),
+                    ));
+                }
+
+                CompositeTable::try_from_fn(|i| {
+                    slots[i].take().ok_or_else(|| {
+                        serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Str(&format!("{:?}", K::VARIANTS[i])),
+                            &"all enum variants must be present",
+                        )
+                    })
+                })
+            }
+        }
+
+        deserializer.deserialize_map(CompositeTableVisitor::<K, V> {
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<K> serde::Serialize for EnumSet<K>
+where
+    K: EnumableTable + serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, K> serde::Deserialize<'de> for EnumSet<K>
+where
+    K: EnumableTable + serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use core::marker::PhantomData;
+        use serde::de::{SeqAccess, Visitor};
+
+        struct EnumSetVisitor<K> {
+            _phantom: PhantomData<K>,
+        }
+
+        impl<'de, K> Visitor<'de> for EnumSetVisitor<K>
+        where
+            K: EnumableTable + serde::Deserialize<'de>,
+        {
+            type Value = EnumSet<K>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a sequence of enum variants")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut set = EnumSet::new();
+                while let Some(variant) = seq.next_element::<K>()? {
+                    set.insert(variant);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_seq(EnumSetVisitor::<K> {
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<K, V, const N: usize> serde::Serialize for EnumMap<K, V, N>
+where
+    K: EnumableTable + serde::Serialize,
+    V: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K, V, const N: usize> serde::Deserialize<'de> for EnumMap<K, V, N>
+where
+    K: EnumableTable + serde::Deserialize<'de>,
+    V: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use core::marker::PhantomData;
+        use serde::de::{MapAccess, Visitor};
+
+        struct EnumMapVisitor<K, V, const N: usize> {
+            _phantom: PhantomData<(K, V)>,
+        }
+
+        impl<'de, K, V, const N: usize> Visitor<'de> for EnumMapVisitor<K, V, N>
+        where
+            K: EnumableTable + serde::Deserialize<'de>,
+            V: serde::Deserialize<'de>,
+        {
+            type Value = EnumMap<K, V, N>;
+
+            fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+                formatter.write_str("a map with enum variants as keys")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut result = EnumMap::new();
+                while let Some((key, value)) = map.next_entry::<K, V>()? {
+                    result.insert(key, value);
+                }
+                Ok(result)
+            }
+        }
+
+        deserializer.deserialize_map(EnumMapVisitor::<K, V, N> {
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<R, C, V> serde::Serialize for EnumMatrix<R, C, V>
+where
+    R: EnumableTable,
+    C: EnumableTable,
+    EnumTableOf<R, EnumTableOf<C, V>>: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_rows().serialize(serializer)
+    }
+}
+
+impl<'de, R, C, V> serde::Deserialize<'de> for EnumMatrix<R, C, V>
+where
+    R: EnumableTable,
+    C: EnumableTable,
+    EnumTableOf<R, EnumTableOf<C, V>>: serde::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        serde::Deserialize::deserialize(deserializer).map(EnumMatrix::from_rows)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,4 +368,109 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn serde_single_and_empty() {
+        #[derive(Debug, Clone, Copy, PartialEq, Enumable, serde::Serialize, serde::Deserialize)]
+        enum Single {
+            Only,
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Enumable, serde::Serialize, serde::Deserialize)]
+        enum Empty {}
+
+        let single = EnumTable::<Single, u8, { Single::COUNT }>::new_with_fn(|_| 1);
+        let json = serde_json::to_string(&single).unwrap();
+        assert_eq!(json, r#"{"Only":1}"#);
+        let deserialized: EnumTable<Single, u8, { Single::COUNT }> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(single, deserialized);
+
+        let empty = EnumTable::<Empty, u8, { Empty::COUNT }>::new_with_fn(|_| 1);
+        let json = serde_json::to_string(&empty).unwrap();
+        assert_eq!(json, "{}");
+        let deserialized: EnumTable<Empty, u8, { Empty::COUNT }> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(empty, deserialized);
+    }
+
+    #[test]
+    fn serde_declaration_order() {
+        #[derive(Debug, Clone, Copy, PartialEq, Enumable, serde::Serialize)]
+        #[enumable(order = "declaration")]
+        #[repr(u8)]
+        enum Column {
+            Name = 2,
+            Size = 0,
+            Modified = 1,
+        }
+
+        let table = EnumTable::<Column, u8, { Column::COUNT }>::new_with_fn(|c| *c as u8);
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(json, r#"{"Name":2,"Size":0,"Modified":1}"#);
+    }
+
+    #[test]
+    fn serde_composite_table() {
+        use crate::AnyEnumTable;
+
+        // JSON map keys are strings, so `None` cannot be one, but `Some` of a unit variant is.
+        let result: Result<EnumTableOf<Option<Color>, u8>, _> =
+            serde_json::from_str(r#"{"Red":1,"Green":2,"Blue":3}"#);
+        assert!(result.is_err());
+
+        let table = EnumTableOf::<Option<Color>, u8>::new_with_fn(|_| 0);
+        assert!(serde_json::to_string(&table).is_err());
+        assert!(serde_json::from_str::<EnumTableOf<Option<Color>, u8>>("[]").is_err());
+    }
+
+    #[test]
+    fn serde_enum_set() {
+        let set = EnumSet::new().with(Color::Blue).with(Color::Red);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, r#"["Red","Blue"]"#);
+
+        let deserialized: EnumSet<Color> =
+            serde_json::from_str(r#"["Blue","Red","Blue"]"#).unwrap();
+        assert_eq!(deserialized, set);
+        assert!(serde_json::from_str::<EnumSet<Color>>(r#"["Purple"]"#).is_err());
+    }
+
+    #[test]
+    fn serde_enum_map() {
+        let mut map = EnumMap::<Color, u8, { Color::COUNT }>::new();
+        map.insert(Color::Blue, 3);
+        map.insert(Color::Red, 1);
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"Red":1,"Blue":3}"#);
+
+        let deserialized: EnumMap<Color, u8, { Color::COUNT }> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, map);
+        let empty: EnumMap<Color, u8, { Color::COUNT }> = serde_json::from_str("{}").unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn serde_enum_matrix() {
+        #[derive(Debug, Clone, Copy, PartialEq, Enumable, serde::Serialize, serde::Deserialize)]
+        enum Size {
+            Small,
+            Large,
+        }
+
+        let matrix = EnumMatrix::<Size, Color, u8>::new_with_fn(|size, color| {
+            size.variant_index() as u8 * 10 + color.variant_index() as u8
+        });
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert_eq!(
+            json,
+            r#"{"Small":{"Red":0,"Green":1,"Blue":2},"Large":{"Red":10,"Green":11,"Blue":12}}"#
+        );
+
+        let deserialized: EnumMatrix<Size, Color, u8> = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, matrix);
+        let missing = r#"{"Small":{"Red":0,"Green":1,"Blue":2},"Large":{"Red":10,"Green":11}}"#;
+        assert!(serde_json::from_str::<EnumMatrix<Size, Color, u8>>(missing).is_err());
+    }
 }
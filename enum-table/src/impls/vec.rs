@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use crate::{EnumTable, Enumable};
 
 /// Error type for `EnumTable::try_from_vec`.
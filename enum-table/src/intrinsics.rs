@@ -1,50 +1,247 @@
-macro_rules! const_operator {
-    ($T:ident,$left:ident ($operator:tt) $right:ident) => {
-        match const { core::mem::size_of::<$T>() } {
-            1 => unsafe { *($left as *const $T as *const u8) $operator *($right as *const $T as *const u8) },
-            2 => unsafe { *($left as *const $T as *const u16) $operator *($right as *const $T as *const u16) },
-            4 => unsafe { *($left as *const $T as *const u32) $operator *($right as *const $T as *const u32) },
-            8 => unsafe { *($left as *const $T as *const u64) $operator *($right as *const $T as *const u64) },
-            16 => unsafe { *($left as *const $T as *const u128) $operator *($right as *const $T as *const u128) },
+/// Sorts the variants of an enum by discriminant value.
+pub const fn sort_variants<const N: usize, T: Copy>(arr: [T; N]) -> [T; N] {
+    sort_by_discriminant(arr, false)
+}
 
-            _ => panic!(
-                "enum-table: Enum discriminants larger than 128 bits are not supported. This is likely due to an extremely large enum or invalid memory layout."
-            ),
+/// Sorts the variants of an enum with a signed representation by discriminant value.
+pub const fn sort_signed_variants<const N: usize, T: Copy>(arr: [T; N]) -> [T; N] {
+    sort_by_discriminant(arr, true)
+}
+
+const fn sort_by_discriminant<const N: usize, T: Copy>(arr: [T; N], signed: bool) -> [T; N] {
+    let order = sorted_order::<N, T>(&arr, signed);
+    let mut sorted = arr;
+    let mut i = 0;
+    while i < N {
+        sorted[i] = arr[order[i]];
+        i += 1;
+    }
+    sorted
+}
+
+/// Returns the indices of `variants` in order of discriminant, compared as signed
+/// integers if `signed` is set, so that `variants[order[0]]` has the smallest one.
+///
+/// Like every sort here, this takes O(N log N) steps of `const` evaluation.
+pub const fn sorted_order<const N: usize, T>(variants: &[T], signed: bool) -> [usize; N] {
+    assert!(
+        variants.len() == N,
+        "enum-table: `variants` must have `N` elements"
+    );
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        keys[i] = discriminant_key(&variants[i], signed);
+        i += 1;
+    }
+    argsort(keys)
+}
+
+/// Returns the position of each of `discriminants`, the discriminants of all variants of
+/// `T` cast to `u128`, once the variants are sorted by discriminant.
+///
+/// The derive macro passes casts rather than the variants themselves, which are much
+/// cheaper for the compiler to evaluate in large enums with explicit discriminants.
+pub const fn sorted_positions<const N: usize, T>(
+    discriminants: [u128; N],
+    signed: bool,
+) -> [usize; N] {
+    let size = core::mem::size_of::<T>();
+    let mut keys = [0; N];
+    let mut i = 0;
+    while i < N {
+        // Casts sign-extend, so keep the bytes of `T` alone, as stored in memory.
+        let bits = if size >= 16 {
+            discriminants[i]
+        } else {
+            discriminants[i] & ((1 << (size * 8)) - 1)
+        };
+        keys[i] = order_bits(bits, size, signed);
+        i += 1;
+    }
+
+    let order = argsort(keys);
+    let mut positions = [0; N];
+    let mut i = 0;
+    while i < N {
+        positions[order[i]] = i;
+        i += 1;
+    }
+    positions
+}
+
+/// Returns `true` if any of `discriminants`, the discriminants of all variants of `T` cast
+/// to `u128`, is negative, which means that `T` has a signed representation.
+///
+/// Casts of signed discriminants sign-extend, so a negative one has bits set beyond the
+/// size of `T`. With a 128-bit representation, a negative `i128` cannot be told apart from
+/// a `u128` above `i128::MAX`, so this panics on either.
+pub const fn has_negative_discriminants<T>(discriminants: &[u128]) -> bool {
+    let size = core::mem::size_of::<T>();
+    let mut i = 0;
+    while i < discriminants.len() {
+        if size >= 16 {
+            assert!(
+                discriminants[i] >> 127 == 0,
+                "enum-table: the sign of 128-bit discriminants above `i128::MAX` or below zero is unknown, derive `Enumable` instead"
+            );
+        } else if discriminants[i] >> (size * 8) != 0 {
+            return true;
         }
-    };
+        i += 1;
+    }
+    false
 }
 
-#[inline(always)]
-pub(crate) const fn const_enum_eq<T>(left: &T, right: &T) -> bool {
-    const_operator!(T, left (==) right)
+/// Moves each of `values` to its position in `positions`, as computed by
+/// [`sorted_positions`], to line per-variant metadata up with `VARIANTS`.
+pub const fn permute<const N: usize, V: Copy>(values: [V; N], positions: &[usize; N]) -> [V; N] {
+    let mut permuted = values;
+    let mut i = 0;
+    while i < N {
+        permuted[positions[i]] = values[i];
+        i += 1;
+    }
+    permuted
 }
 
-#[inline(always)]
-pub(crate) const fn const_enum_lt<T>(left: &T, right: &T) -> bool {
-    const_operator!(T, left (<) right)
+/// Returns the value paired with `name` in `lookup`, which is sorted by name.
+///
+/// With `ignore_case`, the names in `lookup` are lowercase and `name` is compared in
+/// lowercase too.
+pub fn find_name(lookup: &[(&str, usize)], name: &str, ignore_case: bool) -> Option<usize> {
+    lookup
+        .binary_search_by(|(key, _)| {
+            if ignore_case {
+                key.bytes()
+                    .cmp(name.bytes().map(|b| b.to_ascii_lowercase()))
+            } else {
+                (*key).cmp(name)
+            }
+        })
+        .ok()
+        .map(|found| lookup[found].1)
 }
 
-pub const fn sort_variants<const N: usize, T>(mut arr: [T; N]) -> [T; N] {
-    let mut i = 1;
+/// Returns the permutation that sorts `keys`, by heapsort.
+///
+/// Keys that are already sorted, as with implicit discriminants, take a single pass.
+const fn argsort<const N: usize>(mut keys: [u128; N]) -> [usize; N] {
+    let mut order = [0; N];
+    let mut sorted = true;
+    let mut i = 0;
     while i < N {
-        let mut j = i;
-        while j > 0 && const_enum_lt(&arr[j], &arr[j - 1]) {
-            arr.swap(j, j - 1);
-            j -= 1;
+        order[i] = i;
+        if i > 0 && keys[i - 1] > keys[i] {
+            sorted = false;
         }
         i += 1;
     }
-    arr
+    if sorted {
+        return order;
+    }
+
+    let mut start = N / 2;
+    while start > 0 {
+        start -= 1;
+        sift_down(&mut keys, &mut order, start, N);
+    }
+    let mut end = N;
+    while end > 1 {
+        end -= 1;
+        swap(&mut keys, &mut order, 0, end);
+        sift_down(&mut keys, &mut order, 0, end);
+    }
+    order
 }
 
-#[cfg(any(debug_assertions, test))]
-pub(crate) const fn is_sorted<T>(arr: &[T]) -> bool {
-    if arr.is_empty() {
-        return true;
+/// Restores the max-heap property of `keys[..end]` below `root`, moving `order` along.
+const fn sift_down(keys: &mut [u128], order: &mut [usize], mut root: usize, end: usize) {
+    loop {
+        let mut child = 2 * root + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && keys[child] < keys[child + 1] {
+            child += 1;
+        }
+        if keys[root] >= keys[child] {
+            return;
+        }
+        swap(keys, order, root, child);
+        root = child;
     }
+}
+
+/// Swaps two keys along with their entries in `order`.
+///
+/// Copying beats `<[T]>::swap` by a wide margin in `const` evaluation.
+const fn swap(keys: &mut [u128], order: &mut [usize], a: usize, b: usize) {
+    let key = keys[a];
+    keys[a] = keys[b];
+    keys[b] = key;
+    let index = order[a];
+    order[a] = order[b];
+    order[b] = index;
+}
+
+/// Returns a value whose unsigned order matches the order of the discriminant of `value`,
+/// compared as a signed integer if `signed` is set.
+const fn discriminant_key<T>(value: &T, signed: bool) -> u128 {
+    let size = core::mem::size_of::<T>();
+    // SAFETY: enums with a primitive representation are fully initialized integers,
+    // aligned for their size.
+    order_bits(
+        unsafe { read_bits(value as *const T as *const u8, size) },
+        size,
+        signed,
+    )
+}
+
+/// Returns the number of entries in the [`dense_indices`] table of `variants`, or `0` if
+/// the discriminants are too sparse for a table.
+///
+/// This is meant to run at compile time.
+pub const fn dense_span<T>(variants: &[T], signed: bool) -> usize {
+    if variants.is_empty() {
+        return 0;
+    }
+    let (min, max) = discriminant_bounds(variants, signed);
+    let limit = variants.len() * 4 + 16;
+    if max - min >= limit as u128 {
+        0
+    } else {
+        (max - min) as usize + 1
+    }
+}
+
+/// Returns a table mapping each discriminant's distance from the smallest one to the index
+/// of its variant in `variants`, with `usize::MAX` for values that are not discriminants.
+///
+/// `N` must be [`dense_span`] of `variants`.
+pub const fn dense_indices<T, const N: usize>(variants: &[T], signed: bool) -> [usize; N] {
+    let mut indices = [usize::MAX; N];
+    if N == 0 {
+        return indices;
+    }
+    let (min, _) = discriminant_bounds(variants, signed);
+    let mut i = 0;
+    while i < variants.len() {
+        indices[(discriminant_key(&variants[i], signed) - min) as usize] = i;
+        i += 1;
+    }
+    indices
+}
+
+/// Returns `true` if the discriminant of `variants[i]` is `i` for every variant, so that
+/// casting a variant to `usize` yields its index.
+pub const fn discriminants_are_indices<T>(variants: &[T]) -> bool {
+    let size = core::mem::size_of::<T>();
     let mut i = 0;
-    while i < arr.len() - 1 {
-        if !const_enum_lt(&arr[i], &arr[i + 1]) {
+    while i < variants.len() {
+        // SAFETY: enums with a primitive representation are fully initialized integers.
+        let bits = unsafe { read_bits(&variants[i] as *const T as *const u8, size) };
+        if bits != i as u128 {
             return false;
         }
         i += 1;
@@ -52,48 +249,287 @@ pub(crate) const fn is_sorted<T>(arr: &[T]) -> bool {
     true
 }
 
-/// Finds the index of `variant` in the `variants` slice using const-compatible equality.
-///
-/// This function is intended to be called inside `const { }` blocks in the derive macro,
-/// so its O(N) cost is paid at compile time, not runtime.
-pub const fn variant_index_of<T>(variant: &T, variants: &[T]) -> usize {
+/// Returns the smallest and largest [`discriminant_key`] of non-empty `variants`.
+const fn discriminant_bounds<T>(variants: &[T], signed: bool) -> (u128, u128) {
+    let mut min = u128::MAX;
+    let mut max = 0;
     let mut i = 0;
     while i < variants.len() {
-        if const_enum_eq(variant, &variants[i]) {
-            return i;
+        let key = discriminant_key(&variants[i], signed);
+        if key < min {
+            min = key;
+        }
+        if key > max {
+            max = key;
         }
         i += 1;
     }
-    panic!(
-        "enum-table: variant not found in VARIANTS array. This is a bug in the Enumable implementation."
-    )
+    (min, max)
 }
 
-/// Binary search for a variant's index in the sorted `VARIANTS` array.
+/// Returns the index of `variant` in `T::VARIANTS` using only `const`-compatible operations.
 ///
 /// This is a `const fn` used by:
-/// - The default `Enumable::variant_index` implementation (O(log N) fallback).
+/// - The default `Enumable::variant_index` implementation.
 /// - The `get_const`, `get_mut_const`, `set_const`, and `remove_const` methods.
-pub const fn binary_search_index<T: crate::Enumable>(variant: &T) -> usize {
-    let variants = T::VARIANTS;
+///
+/// The lookup strategy is described by [`Enumable::LAYOUT`](crate::Enumable::LAYOUT):
+/// a direct offset or table lookup (O(1)) for dense discriminants, a binary search
+/// (O(log N)) for sparse ones, and a check of each variant or field in turn for
+/// data-carrying enums and structs. Keys whose layout is not supported are rejected at
+/// compile time.
+pub const fn const_variant_index<T: crate::Enumable>(variant: &T) -> usize {
+    const {
+        assert!(
+            T::LAYOUT.is_supported(),
+            "enum-table: `const` lookups are not supported for this key type, since the layout of a payload could not be determined. Use the non-const accessors instead."
+        );
+    }
+    crate::layout::Layout::index_of_key(variant)
+}
+
+/// Returns the variant of `T` whose discriminant is `raw`, looked up through
+/// [`Enumable::LAYOUT`](crate::Enumable::LAYOUT).
+///
+/// # Safety
+///
+/// `R` must be the primitive representation of `T`, such as `u16` for `#[repr(u16)]`.
+pub const unsafe fn variant_from_discriminant<T: crate::Enumable, R>(raw: &R) -> Option<T> {
+    debug_assert!(core::mem::size_of::<R>() == core::mem::size_of::<T>());
+    // SAFETY: `raw` is an integer of the size of `T`, as the caller guarantees.
+    match unsafe { T::LAYOUT.find_discriminant(raw as *const R as *const u8) } {
+        Some(index) => Some(T::VARIANTS[index]),
+        None => None,
+    }
+}
+
+/// Reads `size` bytes at `ptr` as an unsigned integer.
+///
+/// Zero-sized values read as `0`.
+///
+/// # Safety
+///
+/// `ptr` must point to `size` initialized bytes, aligned for an integer of that size.
+pub(crate) const unsafe fn read_bits(ptr: *const u8, size: usize) -> u128 {
+    unsafe {
+        match size {
+            0 => 0,
+            1 => *ptr as u128,
+            2 => *(ptr as *const u16) as u128,
+            4 => *(ptr as *const u32) as u128,
+            8 => *(ptr as *const u64) as u128,
+            16 => *(ptr as *const u128),
+
+            _ => panic!(
+                "enum-table: Enum discriminants larger than 128 bits are not supported. This is likely due to an extremely large enum or invalid memory layout."
+            ),
+        }
+    }
+}
+
+/// Maps the `size`-byte integer `bits` to a value whose unsigned order matches the
+/// integer's order, flipping the sign bit when it is `signed`.
+pub(crate) const fn order_bits(bits: u128, size: usize, signed: bool) -> u128 {
+    if signed && size > 0 {
+        bits ^ (1 << (size * 8 - 1))
+    } else {
+        bits
+    }
+}
+
+/// Binary search for `key` in `len` values of `size` bytes each, sorted as integers,
+/// or visited in sorted order through `order` when it is given.
+///
+/// Returns the position of `key` in sorted order, or `None` if it is not there.
+///
+/// # Safety
+///
+/// `variants` must point to `len` initialized values of `size` bytes, and `key` to one more.
+/// `order`, if given, must be a permutation of `0..len`.
+pub(crate) const unsafe fn binary_search_bits(
+    variants: *const u8,
+    len: usize,
+    size: usize,
+    signed: bool,
+    order: Option<&[usize]>,
+    key: *const u8,
+) -> Option<usize> {
+    let key = order_bits(unsafe { read_bits(key, size) }, size, signed);
     let mut low = 0;
-    let mut high = variants.len();
+    let mut high = len;
 
     while low < high {
         let mid = low + (high - low) / 2;
-        if const_enum_lt(&variants[mid], variant) {
+        if unsafe { sorted_key(variants, size, signed, order, mid) } < key {
             low = mid + 1;
         } else {
             high = mid;
         }
     }
 
-    debug_assert!(
-        low < variants.len() && const_enum_eq(&variants[low], variant),
-        "enum-table: variant not found in VARIANTS via binary search. This is a bug in the Enumable implementation."
-    );
+    if low < len && unsafe { sorted_key(variants, size, signed, order, low) } == key {
+        Some(low)
+    } else {
+        None
+    }
+}
+
+/// The order key of the value at `position` in sorted order, for [`binary_search_bits`].
+///
+/// # Safety
+///
+/// See [`binary_search_bits`]; `position` must be below `len`.
+const unsafe fn sorted_key(
+    variants: *const u8,
+    size: usize,
+    signed: bool,
+    order: Option<&[usize]>,
+    position: usize,
+) -> u128 {
+    let index = match order {
+        Some(order) => order[position],
+        None => position,
+    };
+    order_bits(
+        unsafe { read_bits(variants.add(index * size), size) },
+        size,
+        signed,
+    )
+}
+
+/// Returns `true` if `keys` follow the order of `T::VARIANTS`.
+///
+/// Layouts without `const` lookups cannot be checked and always pass.
+#[cfg(debug_assertions)]
+pub(crate) const fn is_variant_order<T: crate::Enumable>(keys: &[T]) -> bool {
+    if T::LAYOUT.is_sorted() {
+        let mut i = 1;
+        while i < keys.len() {
+            // SAFETY: the layout is sorted and describes the keys.
+            let (previous, current) = unsafe {
+                (
+                    T::LAYOUT.sort_key(&keys[i - 1] as *const T as *const u8),
+                    T::LAYOUT.sort_key(&keys[i] as *const T as *const u8),
+                )
+            };
+            if previous >= current {
+                return false;
+            }
+            i += 1;
+        }
+        return true;
+    }
+    if !T::LAYOUT.is_supported() {
+        return true;
+    }
+    let mut i = 0;
+    while i < keys.len() {
+        if crate::layout::Layout::index_of_key(&keys[i]) != i {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// A `const`-compatible, append-only array used by the derive macro to flatten
+/// the `VARIANTS` of nested `Enumable` types.
+pub struct ArrayBuilder<T, const N: usize> {
+    array: core::mem::MaybeUninit<[T; N]>,
+    len: usize,
+}
+
+impl<T: Copy, const N: usize> ArrayBuilder<T, N> {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self {
+            array: core::mem::MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    pub const fn push(&mut self, value: T) {
+        assert!(self.len < N, "enum-table: too many variants pushed");
+        // SAFETY: `len < N`, so the write stays within the array.
+        unsafe {
+            self.array
+                .as_mut_ptr()
+                .cast::<T>()
+                .add(self.len)
+                .write(value);
+        }
+        self.len += 1;
+    }
+
+    pub const fn build(self) -> [T; N] {
+        assert!(
+            self.len == N,
+            "enum-table: not all variants have been pushed"
+        );
+        // SAFETY: all N elements have been written by `push`.
+        unsafe { self.array.assume_init() }
+    }
+}
+
+/// Byte storage for the `VARIANTS` of generic `Enumable` implementations.
+///
+/// Stable Rust cannot create arrays whose length depends on a generic parameter, nor
+/// borrow generic values for `'static` in a constant. Instead, the variants are written
+/// into a concrete byte buffer that is large enough, and read back as a slice.
+#[repr(C, align(16))]
+pub(crate) struct VariantStorage<const B: usize>(pub(crate) [core::mem::MaybeUninit<u8>; B]);
+
+impl<const B: usize> VariantStorage<B> {
+    pub(crate) const fn new() -> Self {
+        Self([core::mem::MaybeUninit::uninit(); B])
+    }
+
+    /// Writes `value` as the `index`-th element of a `[T]` stored in the buffer.
+    pub(crate) const fn write<T: Copy>(&mut self, index: usize, value: T) {
+        assert!(
+            core::mem::align_of::<T>() <= 16,
+            "enum-table: keys aligned to more than 16 bytes are not supported"
+        );
+        assert!((index + 1) * core::mem::size_of::<T>() <= B);
+        // SAFETY: the buffer is aligned for `T` and large enough to hold `index + 1` values.
+        unsafe { (self.0.as_mut_ptr() as *mut T).add(index).write(value) }
+    }
+}
+
+/// Evaluates to a `&'static [MaybeUninit<u8>]` holding `$count` values of `$ty`, built by
+/// `$build::<.., B>()`, which must return a [`VariantStorage<B>`] for any `B`.
+///
+/// Must be the whole initializer of a constant, so that the buffer is promoted to `'static`.
+macro_rules! variant_storage {
+    ($ty:ty, $count:expr, $build:ident::<$($generic:ty),*>) => {{
+        let bytes = $count * core::mem::size_of::<$ty>();
+        if bytes <= 64 {
+            &$build::<$($generic,)* 64>().0
+        } else if bytes <= 1024 {
+            &$build::<$($generic,)* 1024>().0
+        } else if bytes <= 16384 {
+            &$build::<$($generic,)* 16384>().0
+        } else if bytes <= 262144 {
+            &$build::<$($generic,)* 262144>().0
+        } else if bytes <= 4194304 {
+            &$build::<$($generic,)* 4194304>().0
+        } else {
+            panic!("enum-table: too many variants for a built-in Enumable implementation")
+        }
+    }};
+}
+pub(crate) use variant_storage;
 
-    low
+/// Reinterprets the buffer of [`variant_storage!`] as a slice of `count` values of `T`.
+///
+/// # Safety
+///
+/// The first `count` elements of the buffer must have been written as `T`.
+pub(crate) const unsafe fn stored_variants<T>(
+    storage: &'static [core::mem::MaybeUninit<u8>],
+    count: usize,
+) -> &'static [T] {
+    unsafe { core::slice::from_raw_parts(storage.as_ptr() as *const T, count) }
 }
 
 /// Stable polyfill for `core::array::try_from_fn` (unstable `array_try_from_fn`).
@@ -105,12 +541,11 @@ pub(crate) fn try_collect_array<V, E, const N: usize>(
     mut f: impl FnMut(usize) -> Result<V, E>,
 ) -> Result<[V; N], E> {
     let mut array = core::mem::MaybeUninit::<[V; N]>::uninit();
-    let mut initialized: usize = 0;
 
-    for i in 0..N {
-        match f(i) {
+    for initialized in 0..N {
+        match f(initialized) {
             Ok(v) => unsafe {
-                array.as_mut_ptr().cast::<V>().add(i).write(v);
+                array.as_mut_ptr().cast::<V>().add(initialized).write(v);
             },
             Err(e) => {
                 for i in 0..initialized {
@@ -121,8 +556,6 @@ pub(crate) fn try_collect_array<V, E, const N: usize>(
                 return Err(e);
             }
         }
-
-        initialized += 1;
     }
 
     // SAFETY: all N elements have been initialized in the loop above.
@@ -141,53 +574,54 @@ mod tests {
         Blue = 222,
     }
 
-    // --- const_enum_eq ---
+    // --- sort_variants ---
 
     #[test]
-    fn const_enum_eq_same_variant() {
-        assert!(const_enum_eq(&Color::Red, &Color::Red));
-        assert!(const_enum_eq(&Color::Green, &Color::Green));
-        assert!(const_enum_eq(&Color::Blue, &Color::Blue));
+    fn sort_variants_already_sorted() {
+        let arr = [Color::Green, Color::Red, Color::Blue];
+        let sorted = sort_variants(arr);
+        assert_eq!(sorted, [Color::Green, Color::Red, Color::Blue]);
     }
 
     #[test]
-    fn const_enum_eq_different_variant() {
-        assert!(!const_enum_eq(&Color::Red, &Color::Green));
-        assert!(!const_enum_eq(&Color::Green, &Color::Blue));
-        assert!(!const_enum_eq(&Color::Red, &Color::Blue));
+    fn sort_variants_reverse_order() {
+        let arr = [Color::Blue, Color::Red, Color::Green];
+        let sorted = sort_variants(arr);
+        assert_eq!(sorted, [Color::Green, Color::Red, Color::Blue]);
     }
 
-    // --- const_enum_lt ---
-
     #[test]
-    fn const_enum_lt_ordering() {
-        // Green(11) < Red(33) < Blue(222)
-        assert!(const_enum_lt(&Color::Green, &Color::Red));
-        assert!(const_enum_lt(&Color::Red, &Color::Blue));
-        assert!(const_enum_lt(&Color::Green, &Color::Blue));
-    }
+    fn sort_signed_variants_by_value() {
+        #[repr(i8)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Temp {
+            Cold = -1,
+            Mild = 0,
+            Hot = 1,
+        }
 
-    #[test]
-    fn const_enum_lt_not_less() {
-        assert!(!const_enum_lt(&Color::Red, &Color::Green));
-        assert!(!const_enum_lt(&Color::Blue, &Color::Red));
-        assert!(!const_enum_lt(&Color::Red, &Color::Red));
+        assert_eq!(
+            sort_variants([Temp::Cold, Temp::Mild, Temp::Hot]),
+            [Temp::Mild, Temp::Hot, Temp::Cold]
+        );
+        assert_eq!(
+            sort_signed_variants([Temp::Hot, Temp::Cold, Temp::Mild]),
+            [Temp::Cold, Temp::Mild, Temp::Hot]
+        );
     }
 
-    // --- sort_variants ---
-
     #[test]
-    fn sort_variants_already_sorted() {
-        let arr = [Color::Green, Color::Red, Color::Blue];
-        let sorted = sort_variants(arr);
-        assert_eq!(sorted, [Color::Green, Color::Red, Color::Blue]);
+    fn negative_discriminants() {
+        assert!(has_negative_discriminants::<i8>(&[0, -1i8 as u128, 1]));
+        assert!(!has_negative_discriminants::<u8>(&[0, 255, 1]));
+        assert!(!has_negative_discriminants::<u128>(&[0, i128::MAX as u128]));
+        assert!(!has_negative_discriminants::<i16>(&[]));
     }
 
     #[test]
-    fn sort_variants_reverse_order() {
-        let arr = [Color::Blue, Color::Red, Color::Green];
-        let sorted = sort_variants(arr);
-        assert_eq!(sorted, [Color::Green, Color::Red, Color::Blue]);
+    #[should_panic(expected = "128-bit discriminants")]
+    fn negative_discriminants_128_bits() {
+        has_negative_discriminants::<i128>(&[0, -1i128 as u128]);
     }
 
     #[test]
@@ -204,46 +638,110 @@ mod tests {
         assert_eq!(sorted, []);
     }
 
-    // --- is_sorted ---
-
     #[test]
-    fn is_sorted_sorted_slice() {
-        let arr = [Color::Green, Color::Red, Color::Blue];
-        assert!(is_sorted(&arr));
-    }
+    fn sort_variants_many() {
+        #[repr(u16)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Wide {
+            A = 900,
+            B = 7,
+            C = 300,
+            D = 0,
+            E = 65535,
+            F = 12,
+            G = 301,
+        }
 
-    #[test]
-    fn is_sorted_unsorted_slice() {
-        let arr = [Color::Red, Color::Green, Color::Blue];
-        assert!(!is_sorted(&arr));
+        let sorted = sort_variants([
+            Wide::A,
+            Wide::B,
+            Wide::C,
+            Wide::D,
+            Wide::E,
+            Wide::F,
+            Wide::G,
+        ]);
+        assert_eq!(
+            sorted,
+            [
+                Wide::D,
+                Wide::B,
+                Wide::F,
+                Wide::C,
+                Wide::G,
+                Wide::A,
+                Wide::E
+            ]
+        );
     }
 
-    #[test]
-    fn is_sorted_single_element() {
-        let arr = [Color::Red];
-        assert!(is_sorted(&arr));
-    }
+    // --- sorted_order / sorted_positions ---
 
     #[test]
-    fn is_sorted_empty() {
-        let arr: [Color; 0] = [];
-        assert!(is_sorted(&arr));
+    fn sorted_order_and_positions() {
+        #[repr(i16)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Level {
+            High = 500,
+            Low = -500,
+            Mid = 0,
+            Lowest = -1000,
+        }
+
+        let declared = [Level::High, Level::Low, Level::Mid, Level::Lowest];
+        let order: [usize; 4] = sorted_order(&declared, true);
+        assert_eq!(order, [3, 1, 2, 0]);
+
+        let positions = sorted_positions::<4, Level>(
+            [
+                Level::High as u128,
+                Level::Low as u128,
+                Level::Mid as u128,
+                Level::Lowest as u128,
+            ],
+            true,
+        );
+        assert_eq!(positions, [3, 1, 2, 0]);
+        for (i, &position) in positions.iter().enumerate() {
+            assert_eq!(order[position], i);
+        }
+        assert_eq!(
+            permute(["high", "low", "mid", "lowest"], &positions),
+            ["lowest", "low", "mid", "high"]
+        );
+
+        // Unsigned, negative values compare as large ones.
+        let order: [usize; 4] = sorted_order(&declared, false);
+        assert_eq!(order, [2, 0, 3, 1]);
     }
 
-    // --- variant_index_of ---
+    // --- discriminants_are_indices ---
 
     #[test]
-    fn variant_index_of_finds_each() {
-        let sorted = [Color::Green, Color::Red, Color::Blue];
-        assert_eq!(variant_index_of(&Color::Green, &sorted), 0);
-        assert_eq!(variant_index_of(&Color::Red, &sorted), 1);
-        assert_eq!(variant_index_of(&Color::Blue, &sorted), 2);
+    fn discriminants_are_indices_requires_zero_based_order() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(i8)]
+        enum Step {
+            Back = -1,
+            Stay = 0,
+            Forward = 1,
+        }
+
+        assert!(discriminants_are_indices(&[Step::Stay, Step::Forward]));
+        assert!(!discriminants_are_indices(&[Step::Forward, Step::Stay]));
+        assert!(!discriminants_are_indices(&[
+            Step::Back,
+            Step::Stay,
+            Step::Forward
+        ]));
+        assert!(!discriminants_are_indices(&[Color::Green, Color::Red]));
+        assert!(discriminants_are_indices::<Color>(&[]));
     }
 
-    // --- binary_search_index ---
+    // --- const_variant_index ---
 
     #[test]
-    fn binary_search_index_finds_each() {
+    fn const_variant_index_finds_each() {
         // Uses Enumable impl, so we need the derive
         #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
         #[repr(u8)]
@@ -253,9 +751,20 @@ mod tests {
             Cherry = 200,
         }
         // VARIANTS sorted by discriminant: Banana(10), Apple(50), Cherry(200)
-        assert_eq!(binary_search_index(&Fruit::Banana), 0);
-        assert_eq!(binary_search_index(&Fruit::Apple), 1);
-        assert_eq!(binary_search_index(&Fruit::Cherry), 2);
+        assert_eq!(const_variant_index(&Fruit::Banana), 0);
+        assert_eq!(const_variant_index(&Fruit::Apple), 1);
+        assert_eq!(const_variant_index(&Fruit::Cherry), 2);
+    }
+
+    #[test]
+    fn zero_sized_variants() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+        enum Unit {
+            Only,
+        }
+
+        assert_eq!(sort_variants([Unit::Only]), [Unit::Only]);
+        assert_eq!(const_variant_index(&Unit::Only), 0);
     }
 
     // --- try_collect_array ---
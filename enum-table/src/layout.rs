@@ -0,0 +1,927 @@
+//! Describes how the memory representation of a key maps to its index in
+//! [`Enumable::VARIANTS`].
+//!
+//! `const fn` accessors such as [`EnumTable::get_const`](crate::EnumTable::get_const) cannot call
+//! [`Enumable::variant_index`], because trait methods are not callable in `const` contexts.
+//! Instead, they read the bytes of the key and interpret them according to [`Enumable::LAYOUT`].
+
+use crate::{Enumable, intrinsics};
+
+/// Describes how a key's bytes map to its index in [`Enumable::VARIANTS`].
+///
+/// The default, [`Layout::sorted`], treats the key as a plain discriminant and requires
+/// `VARIANTS` to be sorted by it. The derive macro chooses the right layout automatically,
+/// so manual implementations rarely need anything else.
+#[derive(Clone, Copy)]
+pub struct Layout {
+    kind: Kind,
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    /// The key is a plain discriminant and `VARIANTS` is sorted by it.
+    Sorted {
+        variants: *const u8,
+        len: usize,
+        size: usize,
+        signed: bool,
+    },
+    /// The key is a plain discriminant, and its distance from the smallest one, `first`,
+    /// indexes `indices`, or is the index itself when `indices` is `None`.
+    Dense {
+        first: u128,
+        span: usize,
+        size: usize,
+        signed: bool,
+        indices: Option<&'static [usize]>,
+    },
+    /// The key is a plain discriminant, and `VARIANTS` is sorted by it when visited in
+    /// the order of `order`.
+    Permuted {
+        variants: *const u8,
+        size: usize,
+        signed: bool,
+        order: &'static [usize],
+    },
+    /// The first byte is a tag that selects the arm, and the payload follows it.
+    Tagged {
+        variants: *const u8,
+        size: usize,
+        arms: &'static [Arm],
+    },
+    /// A single payload arm at offset 0, whose invalid values encode the other arms.
+    Niche {
+        variants: *const u8,
+        size: usize,
+        arms: &'static [Arm],
+    },
+    /// A struct whose fields are all keys; the index is mixed-radix over the fields.
+    Product { fields: &'static [Field] },
+    /// A data-carrying enum whose arm is not told by a leading tag byte or a scalar niche,
+    /// such as a niche inside a struct payload, so it is identified by comparing
+    /// [`core::mem::discriminant`] with that of each arm.
+    ///
+    /// Reading the discriminant needs the type of the key, so this layout is only usable
+    /// for the key itself, and not for a payload or field of another key.
+    Discriminant { arms: &'static [Arm] },
+    /// The layout could not be determined; `const` lookups are unavailable.
+    Opaque,
+}
+
+/// One variant of a data-carrying enum, as passed to [`Layout::variants`].
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Arm {
+    /// Index of the arm's first flattened variant in `VARIANTS`.
+    pub base: usize,
+    /// Number of flattened variants the arm contributes to `VARIANTS`.
+    pub count: usize,
+    /// Byte offset of the payload within the enum.
+    pub offset: usize,
+    /// Size of the payload in bytes.
+    pub size: usize,
+    /// Layout of the payload, or `None` for unit variants.
+    pub layout: Option<&'static Layout>,
+}
+
+/// One field of a struct key, as passed to [`Layout::product`].
+#[doc(hidden)]
+#[derive(Clone, Copy)]
+pub struct Field {
+    /// Byte offset of the field within the struct.
+    pub offset: usize,
+    /// Number of variants of the field's type.
+    pub count: usize,
+    /// Size of the field in bytes.
+    pub size: usize,
+    /// Layout of the field's type.
+    pub layout: &'static Layout,
+}
+
+impl Arm {
+    const fn is_inhabited(&self) -> bool {
+        self.count > 0
+    }
+
+    const fn has_payload_bytes(&self) -> bool {
+        self.layout.is_some() && self.size > 0
+    }
+
+    /// Returns the index in `VARIANTS` of the key stored at `ptr`, which is of this arm.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the enum this arm belongs to, of this arm.
+    const unsafe fn index_of(&self, ptr: *const u8) -> usize {
+        match self.layout {
+            Some(layout) if self.size > 0 => {
+                self.base + unsafe { layout.index_of(ptr.add(self.offset)) }
+            }
+            _ => self.base,
+        }
+    }
+}
+
+impl Layout {
+    /// The layout of a plain discriminant whose `VARIANTS` are sorted by value.
+    ///
+    /// `const` lookups binary-search `VARIANTS` (O(log N)), or subtract the first
+    /// discriminant (O(1)) when the discriminants are contiguous.
+    pub const fn sorted<T: Enumable>() -> Self {
+        Self::sorted_by::<T>(false)
+    }
+
+    /// The layout of a plain discriminant with a signed representation, whose `VARIANTS`
+    /// are sorted by value as a signed integer.
+    ///
+    /// `const` lookups binary-search `VARIANTS` (O(log N)), or subtract the first
+    /// discriminant (O(1)) when the discriminants are contiguous.
+    pub const fn signed<T: Enumable>() -> Self {
+        Self::sorted_by::<T>(true)
+    }
+
+    const fn sorted_by<T: Enumable>(signed: bool) -> Self {
+        let variants = T::VARIANTS.as_ptr() as *const u8;
+        let len = T::VARIANTS.len();
+        let size = core::mem::size_of::<T>();
+
+        // SAFETY: `variants` points to `len` valid values of `T`.
+        let first = unsafe { contiguous_from(variants, len, size, signed) };
+        if let Some(first) = first {
+            return Self {
+                kind: Kind::Dense {
+                    first,
+                    span: len,
+                    size,
+                    signed,
+                    indices: None,
+                },
+            };
+        }
+
+        Self {
+            kind: Kind::Sorted {
+                variants,
+                len,
+                size,
+                signed,
+            },
+        }
+    }
+
+    /// The layout of a plain discriminant, looked up in a table indexed by distance from
+    /// the smallest discriminant (O(1)).
+    ///
+    /// `indices` must be [`dense_indices`](crate::__private::dense_indices) of
+    /// `T::VARIANTS`; when it is empty because the discriminants are too sparse,
+    /// `fallback` is returned instead.
+    #[doc(hidden)]
+    pub const fn dense<T: Enumable>(
+        indices: &'static [usize],
+        signed: bool,
+        fallback: Self,
+    ) -> Self {
+        let variants = T::VARIANTS;
+        if indices.is_empty() || variants.is_empty() {
+            return fallback;
+        }
+
+        let size = core::mem::size_of::<T>();
+        let mut first = u128::MAX;
+        let mut identity = indices.len() == variants.len();
+        let mut i = 0;
+        while i < variants.len() {
+            // SAFETY: `variants[i]` is a valid value of `T`.
+            let bits = unsafe { key_bits(&variants[i] as *const T as *const u8, size, signed) };
+            if bits < first {
+                first = bits;
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while identity && i < indices.len() {
+            identity = indices[i] == i;
+            i += 1;
+        }
+
+        Self {
+            kind: Kind::Dense {
+                first,
+                span: indices.len(),
+                size,
+                signed,
+                indices: if identity { None } else { Some(indices) },
+            },
+        }
+    }
+
+    /// A layout that does not support `const` lookups.
+    ///
+    /// Using the `const fn` accessors with keys of this layout is a compile-time error.
+    pub const fn opaque() -> Self {
+        Self { kind: Kind::Opaque }
+    }
+
+    /// The layout of a plain discriminant whose `VARIANTS` are not sorted by value, such
+    /// as enums deriving `Enumable` with `#[enumable(order = "declaration")]`.
+    ///
+    /// `order` lists the indices of `VARIANTS` by increasing discriminant, compared as
+    /// signed integers if `signed` is set, as computed by
+    /// [`sorted_order`](crate::__private::sorted_order).
+    /// `const` lookups binary-search `VARIANTS` through `order` (O(log N)).
+    pub const fn permuted<T: Enumable>(order: &'static [usize], signed: bool) -> Self {
+        assert!(
+            order.len() == T::VARIANTS.len(),
+            "enum-table: `order` must have an entry for each variant"
+        );
+        Self {
+            kind: Kind::Permuted {
+                variants: T::VARIANTS.as_ptr() as *const u8,
+                size: core::mem::size_of::<T>(),
+                signed,
+                order,
+            },
+        }
+    }
+
+    /// Builds the layout of a data-carrying enum from a description of its arms.
+    ///
+    /// The memory layout of such enums is unspecified, so this inspects the sizes and
+    /// offsets reported by `arms` to find a leading tag byte or a niche in a scalar payload,
+    /// and otherwise compares the discriminant of the key with that of each arm. It falls
+    /// back to [`Layout::opaque`] when a payload is itself a key whose layout can only be
+    /// read through its discriminant.
+    ///
+    /// # Safety
+    ///
+    /// `arms` must describe every variant of `T` in the order of `T::VARIANTS`, with
+    /// correct payload offsets, sizes and layouts.
+    #[doc(hidden)]
+    pub const unsafe fn variants<T: Enumable>(arms: &'static [Arm]) -> Self {
+        let variants = T::VARIANTS.as_ptr() as *const u8;
+        let size = core::mem::size_of::<T>();
+
+        let mut dataful = None;
+        let mut dataful_count = 0;
+        let mut tagged = size > 0;
+        let mut i = 0;
+        while i < arms.len() {
+            let arm = &arms[i];
+            if let Some(layout) = arm.layout {
+                if !layout.is_nestable() {
+                    return Self::opaque();
+                }
+            }
+            if arm.is_inhabited() && arm.has_payload_bytes() {
+                dataful = Some(arm);
+                dataful_count += 1;
+                if arm.offset == 0 {
+                    tagged = false;
+                }
+            }
+            i += 1;
+        }
+
+        // No payload covers the first byte, so it holds the tag that identifies the arm.
+        if tagged && unsafe { distinct_tags(variants, size, arms) } {
+            return Self {
+                kind: Kind::Tagged {
+                    variants,
+                    size,
+                    arms,
+                },
+            };
+        }
+
+        // A single scalar payload fills the whole enum, so the other arms are stored as
+        // otherwise-invalid values of that scalar.
+        let niche = match dataful {
+            Some(arm) => match arm.layout {
+                Some(layout) => {
+                    dataful_count == 1 && arm.offset == 0 && arm.size == size && layout.is_scalar()
+                }
+                None => false,
+            },
+            None => size <= 16,
+        };
+        if niche {
+            return Self {
+                kind: Kind::Niche {
+                    variants,
+                    size,
+                    arms,
+                },
+            };
+        }
+
+        Self {
+            kind: Kind::Discriminant { arms },
+        }
+    }
+
+    /// Builds the layout of a struct whose fields are all keys.
+    ///
+    /// `VARIANTS` must enumerate the cartesian product of the fields with the first
+    /// field most significant, so that the index of a value is the mixed-radix number
+    /// formed by the indices of its fields.
+    ///
+    /// # Safety
+    ///
+    /// `fields` must describe every field of the struct in declaration order, with
+    /// correct offsets, counts, sizes and layouts.
+    #[doc(hidden)]
+    pub const unsafe fn product(fields: &'static [Field]) -> Self {
+        let mut i = 0;
+        while i < fields.len() {
+            if !fields[i].layout.is_nestable() {
+                return Self::opaque();
+            }
+            i += 1;
+        }
+        Self {
+            kind: Kind::Product { fields },
+        }
+    }
+
+    /// Returns `true` if `VARIANTS` must be sorted by discriminant for this layout.
+    #[cfg(any(test, debug_assertions))]
+    pub(crate) const fn is_sorted(&self) -> bool {
+        matches!(
+            self.kind,
+            Kind::Sorted { .. } | Kind::Dense { indices: None, .. }
+        )
+    }
+
+    /// Returns the value `VARIANTS` is sorted by for the key stored at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// This layout must be sorted, and `ptr` must point to a valid value of the type
+    /// it describes.
+    #[cfg(debug_assertions)]
+    pub(crate) const unsafe fn sort_key(&self, ptr: *const u8) -> u128 {
+        match self.kind {
+            Kind::Sorted { size, signed, .. }
+            | Kind::Dense {
+                size,
+                signed,
+                indices: None,
+                ..
+            } => unsafe { key_bits(ptr, size, signed) },
+            _ => panic!("enum-table: the layout is not sorted by discriminant"),
+        }
+    }
+
+    /// Returns `true` if `const` lookups are available for this layout.
+    pub(crate) const fn is_supported(&self) -> bool {
+        !matches!(self.kind, Kind::Opaque)
+    }
+
+    /// Returns `true` if this layout can be read through [`Self::index_of`], as the layout
+    /// of a payload or field of another key.
+    const fn is_nestable(&self) -> bool {
+        !matches!(self.kind, Kind::Opaque | Kind::Discriminant { .. })
+    }
+
+    /// Returns the index of `key` in `T::VARIANTS`, looked up through `T::LAYOUT`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T::LAYOUT` is not [supported](Self::is_supported).
+    pub(crate) const fn index_of_key<T: Enumable>(key: &T) -> usize {
+        let ptr = key as *const T as *const u8;
+        let Kind::Discriminant { arms } = T::LAYOUT.kind else {
+            // SAFETY: `key` is a valid value of `T`, which is what `T::LAYOUT` describes.
+            return unsafe { T::LAYOUT.index_of(ptr) };
+        };
+        let discriminant = discriminant_bits(key);
+        let mut i = 0;
+        while i < arms.len() {
+            let arm = &arms[i];
+            if arm.is_inhabited() && discriminant_bits(&T::VARIANTS[arm.base]) == discriminant {
+                // SAFETY: `key` has the discriminant of the arm, so it is of that arm.
+                return unsafe { arm.index_of(ptr) };
+            }
+            i += 1;
+        }
+        not_found()
+    }
+
+    /// Returns `true` if every value of this layout is a single fully-initialized integer.
+    const fn is_scalar(&self) -> bool {
+        matches!(
+            self.kind,
+            Kind::Sorted { .. } | Kind::Dense { .. } | Kind::Permuted { .. } | Kind::Niche { .. }
+        )
+    }
+
+    /// Returns the index in `VARIANTS` of the plain discriminant whose bytes are stored at
+    /// `ptr`, or `None` if no variant has it or the layout is not a plain discriminant.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to an initialized integer of the size of the type this layout
+    /// describes, aligned for it.
+    pub(crate) const unsafe fn find_discriminant(&self, ptr: *const u8) -> Option<usize> {
+        match self.kind {
+            Kind::Sorted {
+                variants,
+                len,
+                size,
+                signed,
+            } => unsafe { intrinsics::binary_search_bits(variants, len, size, signed, None, ptr) },
+            Kind::Dense {
+                first,
+                span,
+                size,
+                signed,
+                indices,
+            } => {
+                let offset = unsafe { key_bits(ptr, size, signed) }.wrapping_sub(first);
+                if offset >= span as u128 {
+                    return None;
+                }
+                match indices {
+                    Some(indices) if indices[offset as usize] == usize::MAX => None,
+                    Some(indices) => Some(indices[offset as usize]),
+                    None => Some(offset as usize),
+                }
+            }
+            Kind::Permuted {
+                variants,
+                size,
+                signed,
+                order,
+            } => {
+                let position = unsafe {
+                    intrinsics::binary_search_bits(
+                        variants,
+                        order.len(),
+                        size,
+                        signed,
+                        Some(order),
+                        ptr,
+                    )
+                };
+                match position {
+                    Some(position) => Some(order[position]),
+                    None => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the index in `VARIANTS` of the key stored at `ptr`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to a valid value of the type this layout describes.
+    pub(crate) const unsafe fn index_of(&self, ptr: *const u8) -> usize {
+        match self.kind {
+            Kind::Sorted { .. } | Kind::Dense { .. } | Kind::Permuted { .. } => {
+                match unsafe { self.find_discriminant(ptr) } {
+                    Some(index) => index,
+                    None => not_found(),
+                }
+            }
+            Kind::Tagged {
+                variants,
+                size,
+                arms,
+            } => {
+                let tag = unsafe { *ptr };
+                let mut i = 0;
+                while i < arms.len() {
+                    let arm = &arms[i];
+                    if arm.is_inhabited() && tag == unsafe { *variants.add(arm.base * size) } {
+                        return unsafe { arm.index_of(ptr) };
+                    }
+                    i += 1;
+                }
+                not_found()
+            }
+            Kind::Niche {
+                variants,
+                size,
+                arms,
+            } => {
+                let bits = unsafe { intrinsics::read_bits(ptr, size) };
+                let mut dataful = None;
+                let mut i = 0;
+                while i < arms.len() {
+                    let arm = &arms[i];
+                    if arm.is_inhabited() {
+                        if arm.has_payload_bytes() {
+                            dataful = Some(arm);
+                        } else if bits
+                            == unsafe { intrinsics::read_bits(variants.add(arm.base * size), size) }
+                        {
+                            return arm.base;
+                        }
+                    }
+                    i += 1;
+                }
+                match dataful {
+                    Some(Arm {
+                        base,
+                        layout: Some(layout),
+                        ..
+                    }) => *base + unsafe { layout.index_of(ptr) },
+                    _ => not_found(),
+                }
+            }
+            Kind::Product { fields } => {
+                let mut index = 0;
+                let mut i = 0;
+                while i < fields.len() {
+                    let field = &fields[i];
+                    index *= field.count;
+                    if field.size > 0 {
+                        index += unsafe { field.layout.index_of(ptr.add(field.offset)) };
+                    }
+                    i += 1;
+                }
+                index
+            }
+            Kind::Discriminant { .. } => {
+                panic!("enum-table: this layout is only readable through `Layout::index_of_key`.")
+            }
+            Kind::Opaque => panic!(
+                "enum-table: `const` lookups are not supported for this key type. Use the non-const accessors instead."
+            ),
+        }
+    }
+}
+
+/// Returns `true` if the first byte is the same for every variant of an arm, and
+/// differs between arms.
+///
+/// # Safety
+///
+/// The first byte of every variant of the described type must be initialized.
+const unsafe fn distinct_tags(variants: *const u8, size: usize, arms: &[Arm]) -> bool {
+    let mut i = 0;
+    while i < arms.len() {
+        let arm = &arms[i];
+        if arm.is_inhabited() {
+            let tag = unsafe { *variants.add(arm.base * size) };
+            let mut j = 1;
+            while j < arm.count {
+                if unsafe { *variants.add((arm.base + j) * size) } != tag {
+                    return false;
+                }
+                j += 1;
+            }
+            let mut k = i + 1;
+            while k < arms.len() {
+                if arms[k].is_inhabited() && unsafe { *variants.add(arms[k].base * size) } == tag {
+                    return false;
+                }
+                k += 1;
+            }
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the bits of [`core::mem::discriminant`] of `value`, to compare with those of
+/// other values of `T`.
+const fn discriminant_bits<T>(value: &T) -> u128 {
+    let discriminant = core::mem::discriminant(value);
+    // SAFETY: `Discriminant<T>` holds the integer discriminant of `T`, which is fully
+    // initialized and aligned for its size.
+    unsafe {
+        intrinsics::read_bits(
+            &discriminant as *const core::mem::Discriminant<T> as *const u8,
+            core::mem::size_of::<core::mem::Discriminant<T>>(),
+        )
+    }
+}
+
+/// Reads the discriminant at `ptr` as a value that orders like the discriminant.
+///
+/// # Safety
+///
+/// `ptr` must point to `size` initialized bytes, aligned for an integer of that size.
+const unsafe fn key_bits(ptr: *const u8, size: usize, signed: bool) -> u128 {
+    intrinsics::order_bits(unsafe { intrinsics::read_bits(ptr, size) }, size, signed)
+}
+
+/// Returns the first discriminant if the `len` values at `variants` are consecutive
+/// integers in increasing order.
+///
+/// # Safety
+///
+/// `variants` must point to `len` initialized values of `size` bytes.
+const unsafe fn contiguous_from(
+    variants: *const u8,
+    len: usize,
+    size: usize,
+    signed: bool,
+) -> Option<u128> {
+    if len == 0 {
+        return None;
+    }
+    let first = unsafe { key_bits(variants, size, signed) };
+    let mut i = 1;
+    while i < len {
+        let bits = unsafe { key_bits(variants.add(i * size), size, signed) };
+        if bits != first.wrapping_add(i as u128) {
+            return None;
+        }
+        i += 1;
+    }
+    Some(first)
+}
+
+const fn not_found() -> ! {
+    panic!(
+        "enum-table: variant not found in VARIANTS. This is a bug in the Enumable implementation."
+    )
+}
+
+/// Returns the byte offset of `payload` within `outer`.
+///
+/// # Safety
+///
+/// `payload` must point into `outer`.
+#[doc(hidden)]
+pub const unsafe fn payload_offset<T, P>(outer: &T, payload: &P) -> usize {
+    unsafe {
+        (payload as *const P as *const u8).offset_from(outer as *const T as *const u8) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Slot {
+        Main,
+        Off,
+        Belt,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Action {
+        Move(Direction),
+        Attack { slot: Slot },
+        Wait,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Step {
+        Go(Direction),
+        Stay,
+    }
+
+    const fn const_index<T: Enumable>(value: &T) -> usize {
+        Layout::index_of_key(value)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    #[repr(u16)]
+    enum Spread {
+        Low = 1,
+        Mid = 500,
+        High = 60000,
+    }
+
+    #[test]
+    fn sorted_layout() {
+        assert!(matches!(Spread::LAYOUT.kind, Kind::Sorted { .. }));
+        assert!(Spread::LAYOUT.is_sorted());
+        for (i, spread) in Spread::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(spread), i);
+        }
+
+        struct Manual;
+        impl Manual {
+            const LAYOUT: Layout = Layout::sorted::<bool>();
+        }
+        assert!(matches!(
+            Manual::LAYOUT.kind,
+            Kind::Dense { indices: None, .. }
+        ));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    #[repr(i8)]
+    enum Gappy {
+        A = -3,
+        B = 0,
+        C = 4,
+    }
+
+    #[test]
+    fn dense_layout() {
+        assert!(matches!(
+            Direction::LAYOUT.kind,
+            Kind::Dense { indices: None, .. }
+        ));
+        assert!(Direction::LAYOUT.is_sorted());
+        assert_eq!(const_index(&Direction::North), 0);
+        assert_eq!(const_index(&Direction::West), 3);
+
+        assert!(matches!(
+            Gappy::LAYOUT.kind,
+            Kind::Dense {
+                span: 8,
+                indices: Some(_),
+                ..
+            }
+        ));
+        assert!(!Gappy::LAYOUT.is_sorted());
+        for (i, gappy) in Gappy::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(gappy), i);
+        }
+
+        assert!(matches!(Priority::LAYOUT.kind, Kind::Dense { .. }));
+        for (i, priority) in Priority::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(priority), i);
+        }
+    }
+
+    #[test]
+    fn find_discriminant() {
+        let find = |layout: Layout, raw: u16| unsafe {
+            layout.find_discriminant(&raw as *const u16 as *const u8)
+        };
+        assert_eq!(find(Spread::LAYOUT, 500), Some(1));
+        assert_eq!(find(Spread::LAYOUT, 501), None);
+
+        let find = |layout: Layout, raw: i8| unsafe {
+            layout.find_discriminant(&raw as *const i8 as *const u8)
+        };
+        assert_eq!(find(Gappy::LAYOUT, -3), Some(0));
+        assert_eq!(find(Gappy::LAYOUT, 4), Some(2));
+        assert_eq!(find(Gappy::LAYOUT, 1), None);
+        assert_eq!(find(Gappy::LAYOUT, 100), None);
+        assert_eq!(find(Action::LAYOUT, 0), None);
+
+        let find = |layout: Layout, raw: u8| unsafe { layout.find_discriminant(&raw as *const u8) };
+        assert_eq!(find(Level::LAYOUT, 200), Some(0));
+        assert_eq!(find(Level::LAYOUT, 100), Some(2));
+        assert_eq!(find(Level::LAYOUT, 50), None);
+    }
+
+    #[test]
+    fn derived_variant_index() {
+        assert_eq!(Direction::West.variant_index(), 3);
+        assert_eq!(Priority::High.variant_index(), 0);
+        assert_eq!(Gappy::C.variant_index(), 2);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    #[enumable(order = "declaration")]
+    #[repr(u8)]
+    enum Priority {
+        High = 2,
+        Low = 0,
+        Normal = 1,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    #[enumable(order = "declaration")]
+    #[repr(u8)]
+    enum Level {
+        High = 200,
+        Low = 0,
+        Normal = 100,
+    }
+
+    #[test]
+    fn permuted_layout() {
+        assert!(matches!(Level::LAYOUT.kind, Kind::Permuted { .. }));
+        assert!(!Level::LAYOUT.is_sorted());
+        for (i, level) in Level::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(level), i);
+        }
+        assert!(<Option<Level>>::LAYOUT.is_scalar());
+    }
+
+    #[test]
+    fn tagged_layout() {
+        assert!(matches!(Action::LAYOUT.kind, Kind::Tagged { .. }));
+        for (i, action) in Action::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(action), i);
+        }
+    }
+
+    #[test]
+    fn niche_layout() {
+        assert!(matches!(Step::LAYOUT.kind, Kind::Niche { .. }));
+        for (i, step) in Step::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(step), i);
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    struct Seat {
+        slot: Slot,
+        facing: Direction,
+    }
+
+    #[test]
+    fn product_layout() {
+        assert!(matches!(Seat::LAYOUT.kind, Kind::Product { .. }));
+        for (i, seat) in Seat::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(seat), i);
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Booking {
+        Seat(Seat),
+        Standing,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Place {
+        Seat(Seat),
+        Slot(Slot),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Ticket {
+        Booked(Booking),
+        Lost,
+    }
+
+    #[test]
+    fn discriminant_layout() {
+        assert!(matches!(Booking::LAYOUT.kind, Kind::Discriminant { .. }));
+        assert!(matches!(Place::LAYOUT.kind, Kind::Discriminant { .. }));
+        assert!(matches!(
+            <Option<Seat>>::LAYOUT.kind,
+            Kind::Discriminant { .. }
+        ));
+        for (i, booking) in Booking::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(booking), i);
+        }
+        for (i, place) in Place::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(place), i);
+        }
+
+        // The payload can only be read through its discriminant, which needs its type.
+        assert!(!Ticket::LAYOUT.is_supported());
+        assert_eq!(Ticket::Lost.variant_index(), Booking::COUNT);
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Unit {
+        Only,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Never {}
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Sparse {
+        Unit(Unit),
+        Never(Never),
+        Go(Direction),
+        Stop,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Wrapper {
+        Only(Unit),
+    }
+
+    #[test]
+    fn zero_sized_payloads() {
+        assert_eq!(Sparse::COUNT, 6);
+        for (i, sparse) in Sparse::VARIANTS.iter().enumerate() {
+            assert_eq!(const_index(sparse), i);
+            assert_eq!(sparse.variant_index(), i);
+        }
+
+        assert_eq!(core::mem::size_of::<Wrapper>(), 0);
+        assert_eq!(Wrapper::VARIANTS, &[Wrapper::Only(Unit::Only)]);
+        assert_eq!(const_index(&Wrapper::Only(Unit::Only)), 0);
+
+        assert_eq!(<Option<Never>>::VARIANTS, &[None]);
+        assert_eq!(const_index(&None::<Never>), 0);
+        assert_eq!(<(Unit, Direction)>::COUNT, 4);
+        assert_eq!(const_index(&(Unit::Only, Direction::South)), 2);
+        assert_eq!(<(Never, Direction)>::COUNT, 0);
+    }
+
+    #[test]
+    fn opaque_layout() {
+        assert!(!Layout::opaque().is_supported());
+        assert!(!Layout::opaque().is_sorted());
+    }
+}
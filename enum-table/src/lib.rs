@@ -1,19 +1,44 @@
 #![doc = include_str!(concat!("../", core::env!("CARGO_PKG_README")))]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[cfg(test)]
 pub extern crate self as enum_table;
 
 use core::marker::PhantomData;
 
 #[cfg(feature = "derive")]
-pub use enum_table_derive::Enumable;
+pub use enum_table_derive::{EnumKind, Enumable};
 
 pub mod builder;
+pub mod composite;
+pub mod counter;
+pub use counter::EnumCounter;
 mod intrinsics;
+pub mod layout;
+pub mod map;
+pub mod matrix;
+pub use map::EnumMap;
+pub use matrix::EnumMatrix;
+pub mod set;
+pub use set::EnumSet;
 
 pub mod __private {
-    pub use crate::intrinsics::{sort_variants, variant_index_of};
+    pub use crate::intrinsics::{
+        ArrayBuilder, dense_indices, dense_span, discriminants_are_indices, find_name,
+        has_negative_discriminants, permute, sort_signed_variants, sort_variants, sorted_order,
+        sorted_positions, variant_from_discriminant,
+    };
+    pub use crate::layout::{Arm, Field, payload_offset};
+
+    /// Creates a table from values in the order of `K::VARIANTS`.
+    pub const fn table_from_array<K: crate::Enumable, V, const N: usize>(
+        values: [V; N],
+    ) -> crate::EnumTable<K, V, N> {
+        crate::EnumTable::new(values)
+    }
 }
 
 mod impls;
@@ -35,17 +60,402 @@ mod macros;
 /// **Note on Padding:** If the enum contains padding bytes (e.g., `#[repr(u8, align(2))]`),
 /// it will cause a **compile-time error** during constant evaluation, as Rust's
 /// constant evaluator does not allow reading uninitialized memory (padding).
+///
+/// # Data-carrying variants
+///
+/// The derive macro also accepts variants holding a single field that is itself
+/// `Enumable`. Such a variant contributes one entry per value of its field, so
+/// `COUNT` is the sum of the payload counts, and `VARIANTS` lists the variants in
+/// declaration order, each expanded in the order of its payload's `VARIANTS`.
+///
+/// ```rust
+/// use enum_table::{EnumTable, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Direction {
+///     North,
+///     South,
+/// }
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Action {
+///     Move(Direction),
+///     Wait,
+/// }
+///
+/// assert_eq!(Action::COUNT, 3);
+/// assert_eq!(
+///     Action::VARIANTS,
+///     &[Action::Move(Direction::North), Action::Move(Direction::South), Action::Wait]
+/// );
+///
+/// let table = EnumTable::<Action, u32, { Action::COUNT }>::new_with_fn(|action| match action {
+///     Action::Move(_) => 1,
+///     Action::Wait => 0,
+/// });
+/// assert_eq!(table.get(&Action::Move(Direction::South)), &1);
+/// ```
+///
+/// The `const fn` accessors such as [`EnumTable::get_const`] find the variant from the
+/// bytes of the key, as described by [`Self::LAYOUT`], and from its
+/// [`discriminant`](core::mem::discriminant). They support every derived key except one
+/// whose payload, or a field of whose payload, is a data-carrying enum that stores its
+/// discriminant inside a struct payload rather than in a leading tag byte or in a payload
+/// that is a plain enum. Using them with such a key is a compile-time error; the other
+/// accessors work for every key.
+///
+/// ```rust,compile_fail
+/// use enum_table::{EnumTable, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone)]
+/// enum Side {
+///     Left,
+///     Right,
+/// }
+///
+/// #[derive(Enumable, Copy, Clone)]
+/// struct Seat {
+///     side: Side,
+///     row: Side,
+/// }
+///
+/// // The discriminant of `Booking` is stored in a niche inside `Seat`.
+/// #[derive(Enumable, Copy, Clone)]
+/// enum Booking {
+///     Seat(Seat),
+///     Standing,
+/// }
+///
+/// #[derive(Enumable, Copy, Clone)]
+/// enum Ticket {
+///     Booked(Booking),
+///     Lost,
+/// }
+///
+/// let table = EnumTable::<Ticket, u8, { Ticket::COUNT }>::new_fill_with_copy(0);
+/// let _ = table.get_const(&Ticket::Lost);
+/// ```
+///
+/// # Struct keys
+///
+/// Structs whose fields are all `Enumable` can derive it as well. `VARIANTS` is
+/// the cartesian product of the fields with the first field most significant, and
+/// `variant_index` is the corresponding mixed-radix number, so `COUNT` is the
+/// product of the field counts.
+///
+/// # Bounded integers
+///
+/// A struct holding a single primitive integer can derive `Enumable` with
+/// `#[enumable(range = 0..16)]` (or an inclusive range such as `1..=9`). `VARIANTS` are the
+/// values of the range in increasing order, `variant_index` is the value minus the start
+/// of the range, and an inherent `const fn new` returns `None` for values outside the range.
+///
+/// `variant_index`, and thus the table accessors, panic for a value outside the range. Keep
+/// the field private so that values outside of your module can only be made through `new`.
+///
+/// ```rust
+/// use enum_table::{EnumTable, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// #[enumable(range = 1..=9)]
+/// struct Floor(u8);
+///
+/// assert_eq!(Floor::COUNT, 9);
+/// assert_eq!(Floor::new(10), None);
+///
+/// let visits = EnumTable::<Floor, u32, { Floor::COUNT }>::new_fill_with_copy(0);
+/// assert_eq!(visits.get(&Floor(9)), &0);
+/// ```
+///
+/// # Built-in implementations
+///
+/// `Enumable` is implemented for `bool`, [`core::cmp::Ordering`], and for `Option<K>`,
+/// `Result<T, E>`, `(A, B)` and `(A, B, C)` of `Enumable` types.
+/// `Option` lists `None` first, `Result` lists every `Ok` before every `Err`,
+/// and tuples are ordered like struct keys.
+///
+/// Other enums that cannot derive `Enumable` can use [`impl_enumable!`].
 pub trait Enumable: Copy + 'static {
     const VARIANTS: &'static [Self];
     const COUNT: usize = Self::VARIANTS.len();
 
-    /// Returns the index of this variant in the sorted `VARIANTS` array.
+    /// Describes how the bytes of a variant map to its index in `VARIANTS`.
+    ///
+    /// This is what the `const fn` accessors such as [`EnumTable::get_const`] use,
+    /// since they cannot call [`Self::variant_index`]. The default, [`layout::Layout::sorted`],
+    /// requires `VARIANTS` to be sorted by discriminant.
+    const LAYOUT: layout::Layout = layout::Layout::sorted::<Self>();
+
+    /// Returns the index of this variant in the `VARIANTS` array.
     ///
     /// When derived via `#[derive(Enumable)]`, this is O(1) at runtime
     /// (using compile-time-computed constants). The default implementation
-    /// falls back to O(log N) binary search for manual implementations.
+    /// falls back to a `const` lookup through [`Self::LAYOUT`], which is an
+    /// O(log N) binary search for manual implementations unless the discriminants
+    /// are contiguous.
     fn variant_index(&self) -> usize {
-        intrinsics::binary_search_index::<Self>(self)
+        intrinsics::const_variant_index::<Self>(self)
+    }
+
+    /// Returns the variant at `index` in the `VARIANTS` array, or `None` if `index` is
+    /// out of bounds.
+    ///
+    /// This is the inverse of [`Self::variant_index`]. Use [`from_index`] in `const` contexts.
+    fn from_index(index: usize) -> Option<Self> {
+        from_index(index)
+    }
+}
+
+/// Conversions between an enum and its primitive discriminant.
+///
+/// `#[derive(Enumable)]` implements this trait for enums with unit variants alone and a
+/// primitive integer representation such as `#[repr(u16)]`, so that raw discriminants
+/// from FFI or binary formats can be decoded without `transmute`.
+///
+/// ```rust
+/// use enum_table::{Enumable, EnumableRepr};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// #[repr(u16)]
+/// enum Opcode {
+///     Read = 1,
+///     Write = 2,
+///     Sync = 8,
+/// }
+///
+/// assert_eq!(Opcode::from_discriminant(8), Some(Opcode::Sync));
+/// assert_eq!(Opcode::from_discriminant(3), None);
+/// assert_eq!(Opcode::Write.discriminant(), 2);
+/// ```
+pub trait EnumableRepr: Enumable {
+    /// The primitive integer representation of the enum.
+    type Repr: Copy;
+
+    /// Returns the variant whose discriminant is `raw`, or `None` if there is none.
+    ///
+    /// When derived, this looks the discriminant up through [`Enumable::LAYOUT`]: a table
+    /// lookup (O(1)) unless the discriminants are sparse, and a binary search otherwise.
+    fn from_discriminant(raw: Self::Repr) -> Option<Self>;
+
+    /// Returns the discriminant of this variant.
+    fn discriminant(&self) -> Self::Repr;
+}
+
+/// The names and doc comments of the variants of an enum.
+///
+/// `#[derive(Enumable)]` implements this trait for enums with unit variants alone. Names
+/// default to the variant identifiers and can be changed with `#[enumable(rename = "...")]`
+/// on a variant, or `#[enumable(rename_all = "...")]` on the enum with one of the case
+/// conventions `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"`,
+/// `"SCREAMING_SNAKE_CASE"`, `"kebab-case"` and `"SCREAMING-KEBAB-CASE"`.
+///
+/// The derive also implements [`core::str::FromStr`] through [`Self::from_name`], which
+/// accepts extra names given with `#[enumable(alias = "...")]` on a variant, and ignores
+/// ASCII case with `#[enumable(ascii_case_insensitive)]` on the enum.
+///
+/// ```rust
+/// use enum_table::{Enumable, EnumableNames};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// #[enumable(rename_all = "kebab-case")]
+/// enum Command {
+///     /// Builds the project.
+///     Build,
+///     /// Removes build artifacts.
+///     CleanAll,
+///     #[enumable(rename = "ls")]
+///     List,
+/// }
+///
+/// assert_eq!(Command::NAMES, &["build", "clean-all", "ls"]);
+/// assert_eq!(Command::CleanAll.name(), "clean-all");
+/// assert_eq!(Command::DOCS, &["Builds the project.", "Removes build artifacts.", ""]);
+///
+/// assert_eq!(Command::from_name("ls"), Some(Command::List));
+/// assert_eq!("clean-all".parse(), Ok(Command::CleanAll));
+/// assert!("clean".parse::<Command>().is_err());
+/// ```
+pub trait EnumableNames: Enumable {
+    /// The name of each variant, in the order of [`Enumable::VARIANTS`].
+    const NAMES: &'static [&'static str];
+
+    /// The doc comment of each variant, in the order of [`Enumable::VARIANTS`].
+    ///
+    /// Lines are joined with `\n` without the space following `///`, and variants without
+    /// a doc comment have an empty string.
+    const DOCS: &'static [&'static str];
+
+    /// Returns the name of this variant.
+    fn name(&self) -> &'static str {
+        Self::NAMES[self.variant_index()]
+    }
+
+    /// Returns the variant named `name`, or `None` if there is none.
+    ///
+    /// When derived, this binary-searches the names and aliases sorted at compile time
+    /// (O(log N)). The default implementation compares `name` with each of [`Self::NAMES`].
+    fn from_name(name: &str) -> Option<Self> {
+        Self::NAMES
+            .iter()
+            .position(|other| *other == name)
+            .and_then(Self::from_index)
+    }
+}
+
+/// Types whose values fall into the variants of an [`Enumable`] kind.
+///
+/// `#[derive(EnumKind)]` implements this trait for any enum, generating a companion enum
+/// named after it with a `Kind` suffix (or `#[enum_kind(name = "...")]`), which has the
+/// same variants without their payloads and derives `Enumable`, along with an inherent
+/// `kind` method. Tables keyed by the kind can then be read with [`EnumTable::get_for`].
+///
+/// ```rust
+/// use enum_table::{EnumKind, EnumTable, Enumable};
+///
+/// #[derive(EnumKind)]
+/// enum Message {
+///     Text(String),
+///     Move { x: i32, y: i32 },
+///     Quit,
+/// }
+///
+/// assert_eq!(MessageKind::COUNT, 3);
+/// assert_eq!(Message::Move { x: 1, y: 2 }.kind(), MessageKind::Move);
+///
+/// let mut counts = EnumTable::<MessageKind, u32, { MessageKind::COUNT }>::new_fill_with_default();
+/// for message in [Message::Text("hi".to_owned()), Message::Quit, Message::Text("bye".to_owned())] {
+///     *counts.get_mut_for(&message) += 1;
+/// }
+/// assert_eq!(counts.get_for(&Message::Text(String::new())), &2);
+/// ```
+pub trait EnumKind {
+    /// The fieldless kind of `Self`.
+    type Kind: Enumable;
+
+    /// Returns the kind of this value.
+    fn kind(&self) -> Self::Kind;
+}
+
+/// Names the table type of an [`Enumable`] key without its variant count.
+///
+/// Generic code over `K: Enumable` cannot write `EnumTable<K, V, { K::COUNT }>` on stable
+/// Rust, nor can collections keyed by `K` such as [`EnumSet<K>`] size their storage. `#[derive(Enumable)]` and [`impl_enumable!`] implement this trait for concrete types,
+/// with [`Self::Table`] being exactly that type, so that [`EnumTableOf<K, V>`] can be used
+/// instead and operated on through [`AnyEnumTable`].
+///
+/// `Option`, `Result` and tuples of keys implementing this trait implement it too, with a
+/// [`CompositeTable`](composite::CompositeTable) as [`Self::Table`].
+///
+/// ```rust
+/// use enum_table::{AnyEnumTable, EnumTable, EnumTableOf, Enumable, EnumableTable};
+///
+/// fn tally<K: EnumableTable>(keys: impl IntoIterator<Item = K>) -> EnumTableOf<K, u32> {
+///     let mut counts = EnumTableOf::<K, u32>::new_with_fn(|_| 0);
+///     for key in keys {
+///         *counts.get_mut(&key) += 1;
+///     }
+///     counts
+/// }
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Coin {
+///     Heads,
+///     Tails,
+/// }
+///
+/// let counts: EnumTable<Coin, u32, { Coin::COUNT }> = tally([Coin::Heads, Coin::Tails, Coin::Heads]);
+/// assert_eq!(counts.get(&Coin::Heads), &2);
+///
+/// let tosses = tally([Some(Coin::Tails), None, Some(Coin::Tails)]);
+/// assert_eq!(tosses.as_slice(), [1, 0, 2]);
+/// ```
+pub trait EnumableTable: Enumable {
+    /// `EnumTable<Self, V, { Self::COUNT }>`.
+    type Table<V>: AnyEnumTable<Self, V>;
+
+    /// `[u64; Self::COUNT.div_ceil(64)]`, the bitmask of an [`EnumSet<Self>`]. Composite keys
+    /// may use a few more words.
+    type Bits: set::Bits;
+
+    /// `[B; Self::COUNT]`, from which the bitmasks of tuples of `Self` are built.
+    type BitsArray<B: set::Bits>: set::Bits;
+}
+
+/// The [`EnumTable`] with a value of type `V` for each variant of `K`.
+///
+/// See [`EnumableTable`].
+pub type EnumTableOf<K, V> = <K as EnumableTable>::Table<V>;
+
+/// The operations of an [`EnumTable<K, V, N>`] for any `N`, for generic code using
+/// [`EnumTableOf<K, V>`].
+///
+/// Each method behaves as the inherent method of the same name. This trait is sealed, and
+/// implemented by [`EnumTable`] and [`CompositeTable`](composite::CompositeTable).
+pub trait AnyEnumTable<K: Enumable, V>:
+    core::ops::Index<K, Output = V>
+    + core::ops::IndexMut<K>
+    + IntoIterator<Item = (K, V)>
+    + composite::private::Storage<V>
+{
+    /// See [`EnumTable::new_with_fn`].
+    fn new_with_fn(f: impl FnMut(&K) -> V) -> Self;
+
+    /// See [`EnumTable::get`].
+    fn get(&self, variant: &K) -> &V;
+
+    /// See [`EnumTable::get_mut`].
+    fn get_mut(&mut self, variant: &K) -> &mut V;
+
+    /// See [`EnumTable::set`].
+    fn set(&mut self, variant: &K, value: V) -> V;
+
+    /// See [`EnumTable::as_slice`].
+    fn as_slice(&self) -> &[V];
+
+    /// See [`EnumTable::as_mut_slice`].
+    fn as_mut_slice(&mut self) -> &mut [V];
+
+    /// See [`EnumTable::iter`].
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        V: 'a,
+    {
+        K::VARIANTS.iter().zip(self.as_slice())
+    }
+
+    /// See [`EnumTable::iter_mut`].
+    fn iter_mut<'a>(&'a mut self) -> impl Iterator<Item = (&'a K, &'a mut V)>
+    where
+        V: 'a,
+    {
+        K::VARIANTS.iter().zip(self.as_mut_slice())
+    }
+}
+
+/// Returns the variant of `K` at `index` in [`Enumable::VARIANTS`], or `None` if `index` is
+/// out of bounds.
+///
+/// This is a `const fn` counterpart of [`Enumable::from_index`].
+///
+/// ```rust
+/// use enum_table::Enumable;
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Color {
+///     Red,
+///     Green,
+/// }
+///
+/// const GREEN: Option<Color> = enum_table::from_index(1);
+/// assert_eq!(GREEN, Some(Color::Green));
+/// assert_eq!(Color::from_index(2), None);
+/// assert_eq!(Color::from_index(Color::Red.variant_index()), Some(Color::Red));
+/// ```
+pub const fn from_index<K: Enumable>(index: usize) -> Option<K> {
+    if index < K::VARIANTS.len() {
+        Some(K::VARIANTS[index])
+    } else {
+        None
     }
 }
 
@@ -71,6 +481,7 @@ pub trait Enumable: Copy + 'static {
 /// If you need to handle cases where a value might not be present or will be set
 /// later, you can use `Option<V>` as the value type: `EnumTable<K, Option<V>, N>`.
 /// The struct provides convenient methods like [`Self::new_fill_with_none`] for this pattern.
+/// [`EnumMap`] stores such partial tables more compactly, with an occupancy bitmask.
 ///
 /// # Type Parameters
 ///
@@ -105,6 +516,7 @@ pub trait Enumable: Copy + 'static {
 /// assert_eq!(table.get(&Color::Green), &"Green");
 /// assert_eq!(table.get(&Color::Blue), &"Blue");
 /// ```
+#[repr(transparent)]
 pub struct EnumTable<K: Enumable, V, const N: usize> {
     table: [V; N],
     _phantom: PhantomData<K>,
@@ -123,11 +535,12 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
 
         #[cfg(debug_assertions)]
         const {
-            // Ensure that the variants are sorted by their discriminants.
+            // Ensure that the variants are in the order described by `K::LAYOUT`, which is
+            // sorted by discriminant unless the implementation chose another order.
             // This is a compile-time check to ensure that the variants are in the correct order.
-            if !intrinsics::is_sorted(K::VARIANTS) {
+            if !intrinsics::is_variant_order(K::VARIANTS) {
                 panic!(
-                    "Enumable: variants are not sorted by discriminant. Use `enum_table::Enumable` derive macro to ensure correct ordering."
+                    "Enumable: variants are not in the order of Enumable::LAYOUT (sorted by discriminant by default). Use `enum_table::Enumable` derive macro to ensure correct ordering."
                 );
             }
         }
@@ -196,6 +609,21 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
         Ok(Self::new(table))
     }
 
+    /// The slot of `variant`.
+    ///
+    /// The bounds check is optimized out when [`Enumable::variant_index`] is known to be in
+    /// range, as with the `*self as usize` the derive emits for contiguous discriminants.
+    #[inline]
+    fn slot(&self, variant: &K) -> &V {
+        &self.table[variant.variant_index()]
+    }
+
+    /// The mutable counterpart of [`Self::slot`].
+    #[inline]
+    fn slot_mut(&mut self, variant: &K) -> &mut V {
+        &mut self.table[variant.variant_index()]
+    }
+
     /// Returns a reference to the value associated with the given enumeration variant.
     ///
     /// Uses O(1) lookup via [`Enumable::variant_index`].
@@ -204,7 +632,7 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
     ///
     /// * `variant` - A reference to an enumeration variant.
     pub fn get(&self, variant: &K) -> &V {
-        &self.table[variant.variant_index()]
+        self.slot(variant)
     }
 
     /// Returns a mutable reference to the value associated with the given enumeration variant.
@@ -215,7 +643,25 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
     ///
     /// * `variant` - A reference to an enumeration variant.
     pub fn get_mut(&mut self, variant: &K) -> &mut V {
-        &mut self.table[variant.variant_index()]
+        self.slot_mut(variant)
+    }
+
+    /// Returns a reference to the value associated with the kind of `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A value whose [`EnumKind::Kind`] is `K`.
+    pub fn get_for<T: EnumKind<Kind = K>>(&self, value: &T) -> &V {
+        self.get(&value.kind())
+    }
+
+    /// Returns a mutable reference to the value associated with the kind of `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - A value whose [`EnumKind::Kind`] is `K`.
+    pub fn get_mut_for<T: EnumKind<Kind = K>>(&mut self, value: &T) -> &mut V {
+        self.get_mut(&value.kind())
     }
 
     /// Sets the value associated with the given enumeration variant.
@@ -231,38 +677,44 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
     ///
     /// The old value associated with the variant.
     pub fn set(&mut self, variant: &K, value: V) -> V {
-        core::mem::replace(&mut self.table[variant.variant_index()], value)
+        core::mem::replace(self.slot_mut(variant), value)
     }
 
     /// Returns a reference to the value associated with the given enumeration variant.
     ///
-    /// This is a `const fn` that uses binary search (O(log N)).
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`]:
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
     /// For O(1) access, use [`Self::get`].
     ///
     /// # Arguments
     ///
     /// * `variant` - A reference to an enumeration variant.
     pub const fn get_const(&self, variant: &K) -> &V {
-        let idx = intrinsics::binary_search_index::<K>(variant);
+        let idx = intrinsics::const_variant_index::<K>(variant);
         &self.table[idx]
     }
 
     /// Returns a mutable reference to the value associated with the given enumeration variant.
     ///
-    /// This is a `const fn` that uses binary search (O(log N)).
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`]:
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
     /// For O(1) access, use [`Self::get_mut`].
     ///
     /// # Arguments
     ///
     /// * `variant` - A reference to an enumeration variant.
     pub const fn get_mut_const(&mut self, variant: &K) -> &mut V {
-        let idx = intrinsics::binary_search_index::<K>(variant);
+        let idx = intrinsics::const_variant_index::<K>(variant);
         &mut self.table[idx]
     }
 
     /// Sets the value associated with the given enumeration variant.
     ///
-    /// This is a `const fn` that uses binary search (O(log N)).
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`]:
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
     /// For O(1) access, use [`Self::set`].
     ///
     /// # Arguments
@@ -274,10 +726,46 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
     ///
     /// The old value associated with the variant.
     pub const fn set_const(&mut self, variant: &K, value: V) -> V {
-        let idx = intrinsics::binary_search_index::<K>(variant);
+        let idx = intrinsics::const_variant_index::<K>(variant);
         core::mem::replace(&mut self.table[idx], value)
     }
 
+    /// Returns the index of the given variant's value in the table.
+    ///
+    /// This is the index of the variant in [`Enumable::VARIANTS`], and the position of its
+    /// value in [`Self::as_slice`].
+    pub fn index_of(&self, variant: &K) -> usize {
+        variant.variant_index()
+    }
+
+    /// Returns the variant whose value is stored at `index`, or `None` if `index` is out of bounds.
+    pub const fn key_at(&self, index: usize) -> Option<K> {
+        from_index(index)
+    }
+
+    /// Returns a reference to the value stored at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// See [`Self::index_of`] for how variants map to indices.
+    pub const fn get_by_index(&self, index: usize) -> Option<&V> {
+        if index < N {
+            Some(&self.table[index])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the value stored at `index`, or `None` if `index` is
+    /// out of bounds.
+    ///
+    /// See [`Self::index_of`] for how variants map to indices.
+    pub const fn get_mut_by_index(&mut self, index: usize) -> Option<&mut V> {
+        if index < N {
+            Some(&mut self.table[index])
+        } else {
+            None
+        }
+    }
+
     /// Returns the number of entries in the table (equal to the number of enum variants).
     pub const fn len(&self) -> usize {
         N
@@ -290,21 +778,24 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
 
     /// Returns a reference to the underlying array of values.
     ///
-    /// Values are ordered by the sorted discriminant of the enum variants.
+    /// Values are in the order of [`Enumable::VARIANTS`], which is sorted by discriminant
+    /// unless the derive was told otherwise.
     pub const fn as_slice(&self) -> &[V] {
         &self.table
     }
 
     /// Returns a mutable reference to the underlying array of values.
     ///
-    /// Values are ordered by the sorted discriminant of the enum variants.
+    /// Values are in the order of [`Enumable::VARIANTS`], which is sorted by discriminant
+    /// unless the derive was told otherwise.
     pub const fn as_mut_slice(&mut self) -> &mut [V] {
         &mut self.table
     }
 
     /// Consumes the table and returns the underlying array of values.
     ///
-    /// Values are ordered by the sorted discriminant of the enum variants.
+    /// Values are in the order of [`Enumable::VARIANTS`], which is sorted by discriminant
+    /// unless the derive was told otherwise.
     pub fn into_array(self) -> [V; N] {
         self.table
     }
@@ -457,6 +948,20 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, V, N> {
     }
 }
 
+impl<K: EnumableRepr, V, const N: usize> EnumTable<K, V, N> {
+    /// Returns a reference to the value associated with the variant whose discriminant is
+    /// `raw`, or `None` if no variant has that discriminant.
+    ///
+    /// This is meant for untrusted input, such as discriminants read from a file.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - The discriminant of an enumeration variant.
+    pub fn get_by_discriminant(&self, raw: K::Repr) -> Option<&V> {
+        K::from_discriminant(raw).map(|variant| self.get(&variant))
+    }
+}
+
 impl<K: Enumable, V, const N: usize> EnumTable<K, Option<V>, N> {
     /// Creates a new `EnumTable` with `None` values for each variant.
     pub const fn new_fill_with_none() -> Self {
@@ -483,13 +988,15 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, Option<V>, N> {
     ///
     /// The previous value, or `None` if the slot was already empty.
     pub fn remove(&mut self, variant: &K) -> Option<V> {
-        self.table[variant.variant_index()].take()
+        self.slot_mut(variant).take()
     }
 
     /// Removes and returns the value associated with the given enumeration variant,
     /// leaving `None` in its place.
     ///
-    /// This is a `const fn` that uses binary search (O(log N)).
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`]:
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
     /// For O(1) access, use [`Self::remove`].
     ///
     /// # Arguments
@@ -500,7 +1007,7 @@ impl<K: Enumable, V, const N: usize> EnumTable<K, Option<V>, N> {
     ///
     /// The previous value, or `None` if the slot was already empty.
     pub const fn remove_const(&mut self, variant: &K) -> Option<V> {
-        let idx = intrinsics::binary_search_index::<K>(variant);
+        let idx = intrinsics::const_variant_index::<K>(variant);
         self.table[idx].take()
     }
 }
@@ -571,6 +1078,51 @@ mod tests {
             Color::Blue => "Blue",
         });
 
+    #[test]
+    fn from_index() {
+        for (i, color) in Color::VARIANTS.iter().enumerate() {
+            assert_eq!(Color::from_index(i), Some(*color));
+            assert_eq!(Color::from_index(color.variant_index()), Some(*color));
+        }
+        assert_eq!(Color::from_index(Color::COUNT), None);
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum Manual {
+            A,
+            B,
+        }
+        impl Enumable for Manual {
+            const VARIANTS: &'static [Self] = &[Manual::A, Manual::B];
+        }
+        assert_eq!(Manual::from_index(1), Some(Manual::B));
+        assert_eq!(Manual::from_index(2), None);
+
+        const GREEN: Option<Color> = crate::from_index(0);
+        assert_eq!(GREEN, Some(Color::Green));
+    }
+
+    #[test]
+    fn index_accessors() {
+        let mut table = TABLES;
+
+        for color in Color::VARIANTS {
+            let index = table.index_of(color);
+            assert_eq!(table.key_at(index), Some(*color));
+            assert_eq!(table.get_by_index(index), Some(table.get(color)));
+        }
+        assert_eq!(table.key_at(Color::COUNT), None);
+        assert_eq!(table.get_by_index(Color::COUNT), None);
+
+        *table
+            .get_mut_by_index(table.index_of(&Color::Blue))
+            .unwrap() = "Cyan";
+        assert_eq!(table.get(&Color::Blue), &"Cyan");
+        assert!(table.get_mut_by_index(Color::COUNT).is_none());
+
+        const FIRST: Option<&&str> = TABLES.get_by_index(0);
+        assert_eq!(FIRST, Some(&"Green"));
+    }
+
     #[test]
     fn new_with_fn() {
         let table =
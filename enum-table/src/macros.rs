@@ -59,6 +59,219 @@ macro_rules! et {
     };
 }
 
+/// A macro to create an [`EnumMatrix`](crate::EnumMatrix) for given row and column
+/// enumerations and a value type, in a `const` context.
+///
+/// # Arguments
+///
+/// * `$row` - The enumeration type of the rows, which implements the `EnumableTable` trait.
+/// * `$column` - The enumeration type of the columns, which implements the `EnumableTable` trait.
+/// * `$value` - The type of values to be associated with each pair of variants.
+/// * `$row_variable`, `$column_variable` - The variable names to use in the closure for each
+///   pair of variants.
+/// * `$($tt:tt)*` - The closure that maps each pair of variants to a value.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_table::{EnumMatrix, Enumable, em};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Role {
+///     Admin,
+///     Guest,
+/// }
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Action {
+///     Read,
+///     Write,
+/// }
+///
+/// const ALLOWED: EnumMatrix<Role, Action, bool> =
+///     em!(Role, Action, bool, |role, action| match (role, action) {
+///         (Role::Admin, _) | (Role::Guest, Action::Read) => true,
+///         (Role::Guest, Action::Write) => false,
+///     });
+///
+/// assert!(ALLOWED.get(&Role::Guest, &Action::Read));
+/// assert!(!ALLOWED.get(&Role::Guest, &Action::Write));
+/// ```
+#[macro_export]
+macro_rules! em {
+    ($row:ty, $column:ty, $value:ty, |$row_variable:ident, $column_variable:ident| $($tt:tt)*) => {
+        $crate::EnumMatrix::<$row, $column, $value>::from_rows($crate::et!(
+            $row,
+            $crate::EnumTableOf<$column, $value>,
+            |$row_variable| $crate::et!($column, $value, |$column_variable| $($tt)*)
+        ))
+    };
+}
+
+/// Declares an enum deriving [`Enumable`](crate::Enumable) along with a `const` table of a
+/// value for each variant, given next to the variant.
+///
+/// The enum derives `Clone`, `Copy` and `Enumable`, and gets an associated constant `TABLE`
+/// with the visibility of the enum, of type `EnumTable<Enum, Value, { Enum::COUNT }>`.
+/// Attributes on the enum and its variants, such as `#[repr(u8)]`, other derives, doc
+/// comments and `#[enumable(...)]` options, are passed through, as are discriminants.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_table::{EnumTable, Enumable, enum_table};
+///
+/// enum_table! {
+///     #[derive(Debug, PartialEq)]
+///     #[repr(u8)]
+///     pub enum Color: &'static str {
+///         /// The color of fire.
+///         Red = 4 => "r",
+///         Green => "g",
+///         Blue = 1 => "b",
+///     }
+/// }
+///
+/// assert_eq!(Color::VARIANTS, &[Color::Blue, Color::Red, Color::Green]);
+/// assert_eq!(Color::TABLE.get(&Color::Green), &"g");
+///
+/// const RED: &str = Color::TABLE.get_const(&Color::Red);
+/// assert_eq!(RED, "r");
+/// ```
+#[cfg(feature = "derive")]
+#[macro_export]
+macro_rules! enum_table {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident: $value:ty {
+            $(
+                $(#[$variant_attr:meta])*
+                $variant:ident $(= $discriminant:expr)? => $variant_value:expr
+            ),* $(,)?
+        }
+    ) => {
+        #[derive(Clone, Copy, $crate::Enumable)]
+        $(#[$attr])*
+        $vis enum $name {
+            $(
+                $(#[$variant_attr])*
+                $variant $(= $discriminant)?,
+            )*
+        }
+
+        impl $name {
+            /// The value declared with each variant.
+            #[allow(dead_code)]
+            $vis const TABLE: $crate::EnumTable<$name, $value, { <$name as $crate::Enumable>::COUNT }> =
+                $crate::et!($name, $value, |variant| match *variant {
+                    $($name::$variant => $variant_value,)*
+                });
+        }
+    };
+}
+
+/// Implements [`Enumable`](crate::Enumable) for an enum with unit variants alone, listing
+/// its variants, for enums that cannot use the derive macro.
+///
+/// The variants can be listed in any order: like the derive macro, this sorts `VARIANTS` by
+/// discriminant at compile time and gives an O(1) `variant_index`. Its `match` over the
+/// listed variants has no wildcard, so a variant missing from the list is a compile error.
+/// Discriminants are compared as signed integers if any of them is negative. With a 128-bit
+/// representation, a discriminant below zero or above `i128::MAX` is a compile error, since
+/// its sign cannot be known; derive `Enumable` for such enums instead.
+///
+/// Rust's orphan rules still apply: the enum must be defined in the calling crate. This
+/// allows crates such as protocol definitions to implement `Enumable` without depending on
+/// the derive macro.
+///
+/// # Example
+///
+/// ```rust
+/// use enum_table::{EnumTable, Enumable, impl_enumable};
+///
+/// mod protocol {
+///     #[derive(Clone, Copy, Debug, PartialEq)]
+///     pub enum Opcode {
+///         Ping = 9,
+///         Data = 2,
+///         Close = 8,
+///     }
+/// }
+///
+/// impl_enumable!(protocol::Opcode { Ping, Data, Close });
+///
+/// use protocol::Opcode;
+/// assert_eq!(Opcode::VARIANTS, &[Opcode::Data, Opcode::Close, Opcode::Ping]);
+/// assert_eq!(Opcode::Ping.variant_index(), 2);
+///
+/// const NAMES: EnumTable<Opcode, &str, { Opcode::COUNT }> =
+///     enum_table::et!(Opcode, &str, |opcode| match opcode {
+///         Opcode::Ping => "ping",
+///         Opcode::Data => "data",
+///         Opcode::Close => "close",
+///     });
+/// assert_eq!(NAMES.get(&Opcode::Close), &"close");
+/// ```
+#[macro_export]
+macro_rules! impl_enumable {
+    ($ty:path { $($variant:ident),* $(,)? }) => {
+        const _: () = {
+            type Enum = $ty;
+
+            /// The declaration ordinals of the listed variants.
+            #[allow(non_camel_case_types, dead_code)]
+            enum Ordinal {
+                $($variant,)*
+            }
+
+            const COUNT: usize = [$(Ordinal::$variant),*].len();
+
+            const DISCRIMINANTS: [u128; COUNT] = [$(Enum::$variant as u128),*];
+
+            const SIGNED: bool = $crate::__private::has_negative_discriminants::<Enum>(&DISCRIMINANTS);
+
+            impl $crate::Enumable for Enum {
+                const VARIANTS: &'static [Self] = &if SIGNED {
+                    $crate::__private::sort_signed_variants([$(Enum::$variant),*])
+                } else {
+                    $crate::__private::sort_variants([$(Enum::$variant),*])
+                };
+
+                const COUNT: usize = COUNT;
+
+                const LAYOUT: $crate::layout::Layout = {
+                    const SPAN: usize = $crate::__private::dense_span(<Enum as $crate::Enumable>::VARIANTS, SIGNED);
+                    const DENSE: [usize; SPAN] = $crate::__private::dense_indices(<Enum as $crate::Enumable>::VARIANTS, SIGNED);
+                    let fallback = if SIGNED {
+                        $crate::layout::Layout::signed::<Enum>()
+                    } else {
+                        $crate::layout::Layout::sorted::<Enum>()
+                    };
+                    $crate::layout::Layout::dense::<Enum>(&DENSE, SIGNED, fallback)
+                };
+
+                fn variant_index(&self) -> usize {
+                    // Discriminants running from 0 in the order of `VARIANTS` are the indices.
+                    if const { $crate::__private::discriminants_are_indices(<Enum as $crate::Enumable>::VARIANTS) } {
+                        return *self as usize;
+                    }
+                    const POSITIONS: [usize; COUNT] =
+                        $crate::__private::sorted_positions::<COUNT, Enum>(DISCRIMINANTS, SIGNED);
+                    POSITIONS[match *self {
+                        $(Enum::$variant => Ordinal::$variant as usize,)*
+                    }]
+                }
+            }
+
+            impl $crate::EnumableTable for Enum {
+                type Table<V> = $crate::EnumTable<Enum, V, COUNT>;
+                type Bits = [u64; COUNT.div_ceil(64)];
+                type BitsArray<B: $crate::set::Bits> = [B; COUNT];
+            }
+        };
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{EnumTable, Enumable};
@@ -83,4 +296,28 @@ mod tests {
         assert_eq!(TABLE.get(&Test::B), &"B");
         assert_eq!(TABLE.get(&Test::C), &"C");
     }
+
+    #[test]
+    fn enum_table_macro() {
+        enum_table! {
+            #[derive(Debug, PartialEq)]
+            #[enumable(order = "declaration")]
+            #[repr(i8)]
+            enum Level: (u8, &'static str) {
+                #[enumable(rename = "dbg")]
+                Debug = 3 => (0, "debug"),
+                Info = -1 => (1, "info"),
+                Error => (2, "error"),
+            }
+        }
+
+        assert_eq!(Level::VARIANTS, &[Level::Debug, Level::Info, Level::Error]);
+        assert_eq!(Level::Error as i8, 0);
+        assert_eq!(Level::TABLE.get(&Level::Info), &(1, "info"));
+        assert_eq!(Level::TABLE.as_slice()[0], (0, "debug"));
+        assert_eq!(crate::EnumableNames::name(&Level::Debug), "dbg");
+
+        const ERROR: &(u8, &str) = Level::TABLE.get_const(&Level::Error);
+        assert_eq!(ERROR.1, "error");
+    }
 }
@@ -0,0 +1,503 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+use crate::{EnumSet, EnumTable, EnumableTable, set};
+
+/// A map from the variants of an enumeration to values, which may hold a value for only
+/// some of the variants.
+///
+/// Unlike `EnumTable<K, Option<V>, N>`, the slots are `MaybeUninit<V>` and the occupied ones
+/// are tracked by an [`EnumSet<K>`], so a map takes `N` values and a bit per variant
+/// whether or not `Option<V>` has a niche, and its [`Self::len`] is a population count.
+/// Entries are visited in the order of [`Enumable::VARIANTS`](crate::Enumable::VARIANTS).
+///
+/// # Type Parameters
+///
+/// * `K`: The enumeration type, which implements [`EnumableTable`] to size the bitmask.
+/// * `V`: The type of values.
+/// * `N`: The number of variants in the enum, as in [`EnumTable`].
+///
+/// # Examples
+///
+/// ```rust
+/// use enum_table::{EnumMap, EnumTable, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Slot {
+///     Head,
+///     Body,
+///     Feet,
+/// }
+///
+/// let mut equipped = EnumMap::<Slot, String, { Slot::COUNT }>::new();
+/// assert_eq!(equipped.insert(Slot::Head, "helmet".to_owned()), None);
+/// equipped.insert(Slot::Feet, "boots".to_owned());
+///
+/// assert_eq!(equipped.len(), 2);
+/// assert_eq!(equipped.get(&Slot::Feet).map(String::as_str), Some("boots"));
+/// assert!(!equipped.contains_key(&Slot::Body));
+/// assert_eq!(equipped.keys().collect::<Vec<_>>(), [Slot::Head, Slot::Feet]);
+///
+/// let table: EnumTable<Slot, Option<String>, { Slot::COUNT }> = equipped.into();
+/// assert_eq!(table.get(&Slot::Body), &None);
+/// ```
+pub struct EnumMap<K: EnumableTable, V, const N: usize> {
+    /// The slot of each variant, initialized if and only if the variant is in `occupied`.
+    slots: [MaybeUninit<V>; N],
+    occupied: EnumSet<K>,
+}
+
+impl<K: EnumableTable, V, const N: usize> EnumMap<K, V, N> {
+    /// Creates an empty map.
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                N == K::COUNT,
+                "EnumMap: N must equal K::COUNT. The const generic N does not match the number of enum variants."
+            );
+        }
+
+        Self {
+            slots: [const { MaybeUninit::uninit() }; N],
+            occupied: EnumSet::new(),
+        }
+    }
+
+    /// Returns a reference to the value in the slot at `index`, if it is occupied.
+    fn get_by_index(&self, index: usize) -> Option<&V> {
+        if self.occupied.bit(index) {
+            // SAFETY: the slot is occupied.
+            Some(unsafe { self.slots[index].assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Removes the value in the slot at `index`, if it is occupied.
+    fn take_by_index(&mut self, index: usize) -> Option<V> {
+        if self.occupied.replace_bit(index, false) {
+            // SAFETY: the slot was occupied, and is no longer marked as such.
+            Some(unsafe { self.slots[index].assume_init_read() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value associated with the given variant, or `None` if
+    /// it has none.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn get(&self, variant: &K) -> Option<&V> {
+        self.get_by_index(variant.variant_index())
+    }
+
+    /// Returns a mutable reference to the value associated with the given variant, or
+    /// `None` if it has none.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn get_mut(&mut self, variant: &K) -> Option<&mut V> {
+        let index = variant.variant_index();
+        if self.occupied.bit(index) {
+            // SAFETY: the slot is occupied.
+            Some(unsafe { self.slots[index].assume_init_mut() })
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the map has a value for the given variant.
+    pub fn contains_key(&self, variant: &K) -> bool {
+        self.occupied.contains(variant)
+    }
+
+    /// Associates a value with the given variant.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    ///
+    /// # Returns
+    ///
+    /// The previous value of the variant, or `None` if it had none.
+    pub fn insert(&mut self, variant: K, value: V) -> Option<V> {
+        let index = variant.variant_index();
+        if self.occupied.replace_bit(index, true) {
+            // SAFETY: the slot was occupied.
+            Some(core::mem::replace(
+                unsafe { self.slots[index].assume_init_mut() },
+                value,
+            ))
+        } else {
+            self.slots[index].write(value);
+            None
+        }
+    }
+
+    /// Removes and returns the value associated with the given variant, or `None` if it
+    /// had none.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn remove(&mut self, variant: &K) -> Option<V> {
+        self.take_by_index(variant.variant_index())
+    }
+
+    /// Removes all values from the map.
+    pub fn clear(&mut self) {
+        let mut indices = core::mem::take(&mut self.occupied).into_iter();
+        while let Some(index) = indices.next_index() {
+            // SAFETY: the slot was occupied, and is no longer marked as such.
+            unsafe { self.slots[index].assume_init_drop() };
+        }
+    }
+
+    /// Returns the number of variants with a value.
+    pub const fn len(&self) -> usize {
+        self.occupied.len()
+    }
+
+    /// Returns `true` if no variant has a value.
+    pub const fn is_empty(&self) -> bool {
+        self.occupied.is_empty()
+    }
+
+    /// Returns the set of variants with a value.
+    pub const fn key_set(&self) -> EnumSet<K> {
+        self.occupied
+    }
+
+    /// Returns an iterator over the variants with a value.
+    pub fn keys(&self) -> set::Iter<K> {
+        self.occupied.iter()
+    }
+
+    /// Returns an iterator over references to the values in the map.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over mutable references to the values in the map.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over references to the key-value pairs in the map.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: &self.slots,
+            indices: self.occupied.iter(),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the key-value pairs in the map.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            slots: self.slots.iter_mut().enumerate(),
+            occupied: self.occupied,
+        }
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> Drop for EnumMap<K, V, N> {
+    fn drop(&mut self) {
+        if core::mem::needs_drop::<V>() {
+            self.clear();
+        }
+    }
+}
+
+/// An iterator over references to the entries of an [`EnumMap`].
+///
+/// Returned by [`EnumMap::iter`].
+pub struct Iter<'a, K: EnumableTable, V> {
+    slots: &'a [MaybeUninit<V>],
+    indices: set::Iter<K>,
+}
+
+impl<'a, K: EnumableTable, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_index()?;
+        // SAFETY: `indices` lists the occupied slots.
+        Some((&K::VARIANTS[index], unsafe {
+            self.slots[index].assume_init_ref()
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<K: EnumableTable, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// An iterator over mutable references to the entries of an [`EnumMap`].
+///
+/// Returned by [`EnumMap::iter_mut`].
+pub struct IterMut<'a, K: EnumableTable, V> {
+    slots: core::iter::Enumerate<core::slice::IterMut<'a, MaybeUninit<V>>>,
+    /// The occupied slots not visited yet.
+    occupied: EnumSet<K>,
+}
+
+impl<'a, K: EnumableTable, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.find_map(|(index, slot)| {
+            self.occupied.replace_bit(index, false).then(|| {
+                // SAFETY: the slot is occupied.
+                (&K::VARIANTS[index], unsafe { slot.assume_init_mut() })
+            })
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.occupied.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: EnumableTable, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+/// An iterator over the entries of an [`EnumMap`], moving them out of the map.
+///
+/// Returned by [`EnumMap::into_iter`].
+pub struct IntoIter<K: EnumableTable, V, const N: usize> {
+    /// The slots of the map, initialized if and only if they are left in `indices`.
+    slots: [MaybeUninit<V>; N],
+    indices: set::Iter<K>,
+}
+
+impl<K: EnumableTable, V, const N: usize> Iterator for IntoIter<K, V, N> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.indices.next_index()?;
+        // SAFETY: the slot is occupied, and `indices` no longer lists it.
+        Some((K::VARIANTS[index], unsafe {
+            self.slots[index].assume_init_read()
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> ExactSizeIterator for IntoIter<K, V, N> {}
+
+impl<K: EnumableTable, V, const N: usize> Drop for IntoIter<K, V, N> {
+    fn drop(&mut self) {
+        for _ in self.by_ref() {}
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> IntoIterator for EnumMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let map = ManuallyDrop::new(self);
+        IntoIter {
+            // SAFETY: `map` is not dropped, so the values are moved into the iterator.
+            slots: unsafe { core::ptr::read(&map.slots) },
+            indices: map.occupied.iter(),
+        }
+    }
+}
+
+impl<'a, K: EnumableTable, V, const N: usize> IntoIterator for &'a EnumMap<K, V, N> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K: EnumableTable, V, const N: usize> IntoIterator for &'a mut EnumMap<K, V, N> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> FromIterator<(K, V)> for EnumMap<K, V, N> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> Extend<(K, V)> for EnumMap<K, V, N> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (variant, value) in iter {
+            self.insert(variant, value);
+        }
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> From<EnumTable<K, Option<V>, N>> for EnumMap<K, V, N> {
+    /// Creates a map of the values that are `Some`.
+    fn from(table: EnumTable<K, Option<V>, N>) -> Self {
+        table
+            .into_iter()
+            .filter_map(|(variant, value)| Some((variant, value?)))
+            .collect()
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> From<EnumMap<K, V, N>> for EnumTable<K, Option<V>, N> {
+    /// Creates a table with `Some` value for the variants in the map and `None` for the others.
+    fn from(mut map: EnumMap<K, V, N>) -> Self {
+        EnumTable::new(core::array::from_fn(|i| map.take_by_index(i)))
+    }
+}
+
+impl<K: EnumableTable, V: Clone, const N: usize> Clone for EnumMap<K, V, N> {
+    fn clone(&self) -> Self {
+        let mut clone = Self::new();
+        let mut indices = self.occupied.iter();
+        while let Some(index) = indices.next_index() {
+            // SAFETY: `indices` lists the occupied slots.
+            let value = unsafe { self.slots[index].assume_init_ref() };
+            clone.slots[index].write(value.clone());
+            clone.occupied.replace_bit(index, true);
+        }
+        clone
+    }
+}
+
+impl<K: EnumableTable + core::fmt::Debug, V: core::fmt::Debug, const N: usize> core::fmt::Debug
+    for EnumMap<K, V, N>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: EnumableTable, V: PartialEq, const N: usize> PartialEq for EnumMap<K, V, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.occupied == other.occupied && self.values().eq(other.values())
+    }
+}
+
+impl<K: EnumableTable, V: Eq, const N: usize> Eq for EnumMap<K, V, N> {}
+
+impl<K: EnumableTable, V: core::hash::Hash, const N: usize> core::hash::Hash for EnumMap<K, V, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.occupied.hash(state);
+        for value in self.values() {
+            value.hash(state);
+        }
+    }
+}
+
+impl<K: EnumableTable, V, const N: usize> Default for EnumMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::Enumable;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Color {
+        Red = 30,
+        Green = 10,
+        Blue = 20,
+    }
+
+    type Map<V> = EnumMap<Color, V, { Color::COUNT }>;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = Map::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert(Color::Red, 1), None);
+        assert_eq!(map.insert(Color::Red, 2), Some(1));
+        assert_eq!(map.insert(Color::Blue, 3), None);
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get(&Color::Red), Some(&2));
+        assert_eq!(map.get(&Color::Green), None);
+        *map.get_mut(&Color::Blue).unwrap() += 1;
+        assert!(map.get_mut(&Color::Green).is_none());
+        assert!(map.contains_key(&Color::Blue));
+
+        assert_eq!(map.remove(&Color::Blue), Some(4));
+        assert_eq!(map.remove(&Color::Blue), None);
+        assert_eq!(map.key_set(), EnumSet::new().with(Color::Red));
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn iteration_order() {
+        let mut map: Map<&str> = [(Color::Red, "r"), (Color::Green, "g")]
+            .into_iter()
+            .collect();
+        assert_eq!(map.keys().collect::<Vec<_>>(), [Color::Green, Color::Red]);
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            [(&Color::Green, &"g"), (&Color::Red, &"r")]
+        );
+        assert_eq!(map.iter().len(), 2);
+
+        for (color, value) in &mut map {
+            if *color == Color::Red {
+                *value = "red";
+            }
+        }
+        assert_eq!(map.values().collect::<Vec<_>>(), [&"g", &"red"]);
+        assert_eq!(format!("{map:?}"), r#"{Green: "g", Red: "red"}"#);
+        assert_eq!(
+            map.into_iter().collect::<Vec<_>>(),
+            [(Color::Green, "g"), (Color::Red, "red")]
+        );
+    }
+
+    #[test]
+    fn drops_occupied_slots() {
+        let value = Rc::new(());
+        let mut map = Map::new();
+        map.insert(Color::Red, value.clone());
+        map.insert(Color::Blue, value.clone());
+        map.insert(Color::Blue, value.clone());
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        let clone = map.clone();
+        assert_eq!(Rc::strong_count(&value), 5);
+        assert_eq!(clone, map);
+
+        map.remove(&Color::Red);
+        assert_eq!(Rc::strong_count(&value), 4);
+        drop(map);
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        let mut iter = clone.into_iter();
+        assert!(iter.next().is_some());
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn table_conversions() {
+        let table = EnumTable::<Color, Option<u8>, { Color::COUNT }>::new_with_fn(|color| {
+            (*color != Color::Blue).then_some(*color as u8)
+        });
+        let map = Map::from(table);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&Color::Green), Some(&10));
+
+        let roundtrip: EnumTable<Color, Option<u8>, { Color::COUNT }> = map.into();
+        assert_eq!(roundtrip, table);
+    }
+}
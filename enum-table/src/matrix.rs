@@ -0,0 +1,438 @@
+use core::fmt::Write;
+
+use crate::{AnyEnumTable, EnumTableOf, EnumableTable};
+
+/// A two-dimensional table with a value for each pair of a variant of `R`, the row, and a
+/// variant of `C`, the column.
+///
+/// The rows are stored inline as an [`EnumTableOf<R, EnumTableOf<C, V>>`](EnumTableOf), so the
+/// `R::COUNT * C::COUNT` values are laid out in row-major order without any indirection.
+/// As with [`EnumTable`](crate::EnumTable), the accessors locate variants through
+/// [`Enumable::variant_index`](crate::Enumable::variant_index), and iteration follows the order
+/// of [`Enumable::VARIANTS`](crate::Enumable::VARIANTS).
+///
+/// Use the [`crate::em`] macro to create a matrix in a `const` context.
+///
+/// # Examples
+///
+/// ```rust
+/// use enum_table::{EnumMatrix, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Attack {
+///     Slash,
+///     Pierce,
+/// }
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Armor {
+///     Light,
+///     Heavy,
+/// }
+///
+/// let damage = EnumMatrix::<Attack, Armor, f32>::new_with_fn(|attack, armor| match (attack, armor) {
+///     (Attack::Slash, Armor::Heavy) => 0.5,
+///     (Attack::Pierce, Armor::Light) => 1.5,
+///     _ => 1.0,
+/// });
+/// assert_eq!(damage.get(&Attack::Slash, &Armor::Heavy), &0.5);
+/// assert_eq!(damage.row(&Attack::Pierce).as_slice(), &[1.5, 1.0]);
+///
+/// let heavy: Vec<_> = damage.column(&Armor::Heavy).iter().collect();
+/// assert_eq!(heavy, [(&Attack::Slash, &0.5), (&Attack::Pierce, &1.0)]);
+///
+/// let resistance = damage.transpose();
+/// assert_eq!(resistance.get(&Armor::Heavy, &Attack::Slash), &0.5);
+/// ```
+pub struct EnumMatrix<R: EnumableTable, C: EnumableTable, V> {
+    rows: EnumTableOf<R, EnumTableOf<C, V>>,
+}
+
+impl<R: EnumableTable, C: EnumableTable, V> EnumMatrix<R, C, V> {
+    /// Creates a matrix by calling `f` with each pair of a row and a column variant, row by
+    /// row.
+    pub fn new_with_fn(mut f: impl FnMut(&R, &C) -> V) -> Self {
+        Self {
+            rows: EnumTableOf::<R, EnumTableOf<C, V>>::new_with_fn(|row| {
+                EnumTableOf::<C, V>::new_with_fn(|column| f(row, column))
+            }),
+        }
+    }
+
+    /// Creates a matrix from a table of its rows.
+    pub const fn from_rows(rows: EnumTableOf<R, EnumTableOf<C, V>>) -> Self {
+        Self { rows }
+    }
+
+    /// Returns the table of the rows of the matrix.
+    pub const fn as_rows(&self) -> &EnumTableOf<R, EnumTableOf<C, V>> {
+        &self.rows
+    }
+
+    /// Converts the matrix into the table of its rows.
+    pub fn into_rows(self) -> EnumTableOf<R, EnumTableOf<C, V>> {
+        self.rows
+    }
+
+    /// Returns a reference to the value at a row and a column.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn get(&self, row: &R, column: &C) -> &V {
+        self.rows.get(row).get(column)
+    }
+
+    /// Returns a mutable reference to the value at a row and a column.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn get_mut(&mut self, row: &R, column: &C) -> &mut V {
+        self.rows.get_mut(row).get_mut(column)
+    }
+
+    /// Sets the value at a row and a column, returning the old value.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn set(&mut self, row: &R, column: &C, value: V) -> V {
+        self.rows.get_mut(row).set(column, value)
+    }
+
+    /// Returns the table of the values in a row.
+    pub fn row(&self, row: &R) -> &EnumTableOf<C, V> {
+        self.rows.get(row)
+    }
+
+    /// Returns the mutable table of the values in a row.
+    pub fn row_mut(&mut self, row: &R) -> &mut EnumTableOf<C, V> {
+        self.rows.get_mut(row)
+    }
+
+    /// Returns a view of the values in a column.
+    pub fn column(&self, column: &C) -> Column<'_, R, C, V> {
+        Column {
+            matrix: self,
+            column: *column,
+        }
+    }
+
+    /// Returns an iterator over the rows and mutable references to their values in a
+    /// column.
+    pub fn column_mut(&mut self, column: &C) -> impl Iterator<Item = (&R, &mut V)> {
+        let column = *column;
+        self.rows
+            .iter_mut()
+            .map(move |(row, values)| (row, values.get_mut(&column)))
+    }
+
+    /// Returns an iterator over the rows and their tables of values.
+    pub fn rows(&self) -> impl Iterator<Item = (&R, &EnumTableOf<C, V>)> {
+        self.rows.iter()
+    }
+
+    /// Returns an iterator over the views of the columns.
+    pub fn columns(&self) -> impl Iterator<Item = Column<'_, R, C, V>> {
+        C::VARIANTS.iter().map(|column| self.column(column))
+    }
+
+    /// Returns an iterator over the rows, columns and values, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (&R, &C, &V)> {
+        self.rows.iter().flat_map(|(row, values)| {
+            values
+                .iter()
+                .map(move |(column, value)| (row, column, value))
+        })
+    }
+
+    /// Returns an iterator over the rows, columns and mutable references to the values, row
+    /// by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&R, &C, &mut V)> {
+        self.rows.iter_mut().flat_map(|(row, values)| {
+            values
+                .iter_mut()
+                .map(move |(column, value)| (row, column, value))
+        })
+    }
+
+    /// Converts the matrix into its transpose, whose rows are the columns of this matrix.
+    pub fn transpose(self) -> EnumMatrix<C, R, V> {
+        let mut cells = EnumMatrix::<C, R, Option<V>>::new_with_fn(|_, _| None);
+        for (row, values) in self.rows {
+            for (column, value) in values {
+                cells.set(&column, &row, Some(value));
+            }
+        }
+        EnumMatrix::new_with_fn(|column, row| match cells.get_mut(column, row).take() {
+            Some(value) => value,
+            None => unreachable!("EnumMatrix: every cell is moved exactly once"),
+        })
+    }
+}
+
+/// A view of the values in a column of an [`EnumMatrix`].
+///
+/// Returned by [`EnumMatrix::column`] and [`EnumMatrix::columns`].
+pub struct Column<'a, R: EnumableTable, C: EnumableTable, V> {
+    matrix: &'a EnumMatrix<R, C, V>,
+    column: C,
+}
+
+impl<'a, R: EnumableTable, C: EnumableTable, V> Column<'a, R, C, V> {
+    /// Returns the column variant of this view.
+    pub fn key(&self) -> &C {
+        &self.column
+    }
+
+    /// Returns a reference to the value at a row of this column.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn get(&self, row: &R) -> &'a V {
+        self.matrix.get(row, &self.column)
+    }
+
+    /// Returns an iterator over the rows and their values in this column.
+    pub fn iter(&self) -> impl Iterator<Item = (&'a R, &'a V)> + use<'a, R, C, V> {
+        let column = self.column;
+        self.matrix
+            .rows
+            .iter()
+            .map(move |(row, values)| (row, values.get(&column)))
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V> Clone for Column<'_, R, C, V> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V> Copy for Column<'_, R, C, V> {}
+
+impl<R: EnumableTable, C: EnumableTable, V> core::ops::Index<(R, C)> for EnumMatrix<R, C, V> {
+    type Output = V;
+
+    fn index(&self, (row, column): (R, C)) -> &Self::Output {
+        self.get(&row, &column)
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V> core::ops::IndexMut<(R, C)> for EnumMatrix<R, C, V> {
+    fn index_mut(&mut self, (row, column): (R, C)) -> &mut Self::Output {
+        self.get_mut(&row, &column)
+    }
+}
+
+/// Counts the characters written to it, to align the cells of the grid.
+struct Width(usize);
+
+impl Write for Width {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+fn debug_width(value: &impl core::fmt::Debug) -> usize {
+    let mut width = Width(0);
+    let _ = write!(width, "{value:?}");
+    width.0
+}
+
+/// Formats the matrix as a grid, with the columns in the first line and a row in each of
+/// the next ones.
+impl<R, C, V> core::fmt::Debug for EnumMatrix<R, C, V>
+where
+    R: EnumableTable + core::fmt::Debug,
+    C: EnumableTable + core::fmt::Debug,
+    V: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let label_width = R::VARIANTS.iter().map(debug_width).max().unwrap_or(0);
+        let widths = EnumTableOf::<C, usize>::new_with_fn(|column| {
+            self.column(column)
+                .iter()
+                .map(|(_, value)| debug_width(value))
+                .fold(debug_width(column), usize::max)
+        });
+        let last = C::COUNT.wrapping_sub(1);
+
+        write!(f, "{:label_width$}", "")?;
+        for (i, column) in C::VARIANTS.iter().enumerate() {
+            f.write_str("  ")?;
+            let width = debug_width(column);
+            write!(f, "{column:?}")?;
+            if i != last {
+                write!(f, "{:1$}", "", widths.get(column) - width)?;
+            }
+        }
+
+        for (row, values) in self.rows() {
+            f.write_char('\n')?;
+            write!(f, "{row:?}{:1$}", "", label_width - debug_width(row))?;
+            for (i, (column, value)) in values.iter().enumerate() {
+                f.write_str("  ")?;
+                write!(f, "{value:?}")?;
+                if i != last {
+                    write!(f, "{:1$}", "", widths.get(column) - debug_width(value))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V> Clone for EnumMatrix<R, C, V>
+where
+    EnumTableOf<R, EnumTableOf<C, V>>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from_rows(self.rows.clone())
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V> Copy for EnumMatrix<R, C, V> where
+    EnumTableOf<R, EnumTableOf<C, V>>: Copy
+{
+}
+
+impl<R: EnumableTable, C: EnumableTable, V: PartialEq> PartialEq for EnumMatrix<R, C, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.rows
+            .as_slice()
+            .iter()
+            .zip(other.rows.as_slice())
+            .all(|(a, b)| a.as_slice() == b.as_slice())
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V: Eq> Eq for EnumMatrix<R, C, V> {}
+
+impl<R: EnumableTable, C: EnumableTable, V: core::hash::Hash> core::hash::Hash
+    for EnumMatrix<R, C, V>
+{
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        for values in self.rows.as_slice() {
+            values.as_slice().hash(state);
+        }
+    }
+}
+
+impl<R: EnumableTable, C: EnumableTable, V: Default> Default for EnumMatrix<R, C, V> {
+    fn default() -> Self {
+        Self::new_with_fn(|_, _| V::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Enumable;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Role {
+        Admin = 20,
+        Guest = 10,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Action {
+        Read,
+        Write,
+        Delete,
+    }
+
+    const PERMISSIONS: EnumMatrix<Role, Action, bool> =
+        crate::em!(Role, Action, bool, |role, action| match (role, action) {
+            (Role::Admin, _) => true,
+            (Role::Guest, Action::Read) => true,
+            (Role::Guest, _) => false,
+        });
+
+    #[test]
+    fn get_and_set() {
+        let mut matrix = PERMISSIONS;
+        assert!(matrix.get(&Role::Admin, &Action::Delete));
+        assert!(!matrix[(Role::Guest, Action::Write)]);
+        assert!(!matrix.set(&Role::Guest, &Action::Write, true));
+        matrix[(Role::Admin, Action::Delete)] = false;
+        *matrix.get_mut(&Role::Guest, &Action::Read) = false;
+        assert_eq!(matrix.row(&Role::Guest).as_slice(), &[false, true, false]);
+        assert_eq!(matrix.row(&Role::Admin).as_slice(), &[true, true, false]);
+        assert_ne!(matrix, PERMISSIONS);
+        assert_eq!(
+            EnumMatrix::<Role, Action, bool>::default()
+                .iter()
+                .filter(|(_, _, v)| **v)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut matrix = EnumMatrix::<Role, Action, usize>::new_with_fn(|role, action| {
+            role.variant_index() * 10 + action.variant_index()
+        });
+        assert_eq!(
+            matrix
+                .iter()
+                .map(|(_, _, value)| *value)
+                .collect::<Vec<_>>(),
+            [0, 1, 2, 10, 11, 12]
+        );
+        assert_eq!(
+            matrix.rows().map(|(role, _)| *role).collect::<Vec<_>>(),
+            [Role::Guest, Role::Admin]
+        );
+
+        let column = matrix.column(&Action::Write);
+        assert_eq!(column.key(), &Action::Write);
+        assert_eq!(column.get(&Role::Admin), &11);
+        assert_eq!(
+            matrix
+                .columns()
+                .map(|column| column.iter().map(|(_, value)| *value).sum::<usize>())
+                .collect::<Vec<_>>(),
+            [10, 12, 14]
+        );
+
+        for (_, value) in matrix.column_mut(&Action::Delete) {
+            *value = 0;
+        }
+        matrix.row_mut(&Role::Guest).set(&Action::Read, 7);
+        for (_, _, value) in matrix.iter_mut() {
+            *value += 1;
+        }
+        assert_eq!(matrix.row(&Role::Guest).as_slice(), &[8, 2, 1]);
+        assert_eq!(matrix.row(&Role::Admin).as_slice(), &[11, 12, 1]);
+    }
+
+    #[test]
+    fn transpose() {
+        let matrix = EnumMatrix::<Role, Action, String>::new_with_fn(|role, action| {
+            format!("{role:?}/{action:?}")
+        });
+        let transposed = matrix.clone().transpose();
+        assert_eq!(
+            transposed.get(&Action::Delete, &Role::Guest),
+            "Guest/Delete"
+        );
+        assert_eq!(
+            transposed.row(&Action::Read).as_slice(),
+            ["Guest/Read", "Admin/Read"]
+        );
+        assert_eq!(transposed.transpose(), matrix);
+    }
+
+    #[test]
+    fn debug_grid() {
+        let matrix =
+            EnumMatrix::<Role, Action, u32>::new_with_fn(|role, action| match (role, action) {
+                (Role::Admin, Action::Delete) => 1000,
+                _ => 1,
+            });
+        assert_eq!(
+            format!("{matrix:?}"),
+            "       Read  Write  Delete\n\
+             Guest  1     1      1\n\
+             Admin  1     1      1000"
+        );
+    }
+}
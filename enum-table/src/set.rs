@@ -0,0 +1,683 @@
+use core::marker::PhantomData;
+use core::ops::{BitAnd, BitOr, BitXor, Not, Sub};
+
+use crate::composite::Concat;
+use crate::{EnumTable, EnumableTable, intrinsics};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// The words of the bitmask of an [`EnumSet`]: `[u64; W]` for some `W`, or arrays and
+/// [`Concat`]s of those for composite keys, all of which are laid out as `[u64; W]`.
+///
+/// This trait is sealed, and [`EnumableTable::Bits`] must have a bit for each variant.
+pub trait Bits: Copy + 'static + private::Sealed {
+    /// The bitmask with no bits set.
+    const EMPTY: Self;
+}
+
+impl private::Sealed for u64 {}
+
+impl Bits for u64 {
+    const EMPTY: Self = 0;
+}
+
+impl<B: Bits, const W: usize> private::Sealed for [B; W] {}
+
+impl<B: Bits, const W: usize> Bits for [B; W] {
+    const EMPTY: Self = [B::EMPTY; W];
+}
+
+impl<A: Bits, B: Bits> private::Sealed for Concat<A, B> {}
+
+impl<A: Bits, B: Bits> Bits for Concat<A, B> {
+    const EMPTY: Self = Concat(A::EMPTY, B::EMPTY);
+}
+
+/// A set of variants of an enumeration, stored as a bitmask.
+///
+/// Each variant is a bit of [`EnumableTable::Bits`], at its index in [`Enumable::VARIANTS`](crate::Enumable::VARIANTS),
+/// so the set takes `K::COUNT.div_ceil(64)` words, or a few more for composite keys such as
+/// `Option<K>`, and the set operations work a word at a time. Iteration follows the order
+/// of `VARIANTS`.
+///
+/// As with [`EnumTable`], the accessors locate variants through [`Enumable::variant_index`](crate::Enumable::variant_index),
+/// and the `_const` ones through [`Enumable::LAYOUT`](crate::Enumable::LAYOUT).
+///
+/// # Examples
+///
+/// ```rust
+/// use enum_table::{EnumSet, Enumable};
+///
+/// #[derive(Enumable, Copy, Clone, Debug, PartialEq)]
+/// enum Permission {
+///     Read,
+///     Write,
+///     Execute,
+/// }
+///
+/// const READ_WRITE: EnumSet<Permission> = EnumSet::new()
+///     .with(Permission::Read)
+///     .with(Permission::Write);
+///
+/// let granted: EnumSet<Permission> = [Permission::Read, Permission::Execute].into_iter().collect();
+/// assert!(granted.contains(&Permission::Execute));
+/// assert_eq!((granted & READ_WRITE).iter().collect::<Vec<_>>(), [Permission::Read]);
+/// assert_eq!(READ_WRITE.complement(), EnumSet::new().with(Permission::Execute));
+/// assert!(!READ_WRITE.is_subset(&granted));
+/// ```
+pub struct EnumSet<K: EnumableTable> {
+    bits: K::Bits,
+    _phantom: PhantomData<K>,
+}
+
+impl<K: EnumableTable> EnumSet<K> {
+    /// The number of words in the bitmask.
+    const WORDS: usize = {
+        assert!(
+            core::mem::size_of::<K::Bits>() >= K::COUNT.div_ceil(64) * 8,
+            "EnumSet: EnumableTable::Bits must have a bit for each variant."
+        );
+        core::mem::size_of::<K::Bits>() / 8
+    };
+
+    /// Creates an empty set.
+    pub const fn new() -> Self {
+        Self {
+            bits: K::Bits::EMPTY,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Creates a set of all variants.
+    pub const fn all() -> Self {
+        Self::new().complement()
+    }
+
+    /// Creates a set from a bitmask, where bit `i % 64` of word `i / 64` stands for the
+    /// variant at index `i` in [`Enumable::VARIANTS`](crate::Enumable::VARIANTS).
+    ///
+    /// Bits past the last variant are ignored.
+    pub const fn from_bits(bits: K::Bits) -> Self {
+        let mut set = Self {
+            bits,
+            _phantom: PhantomData,
+        };
+        set.clear_unused();
+        set
+    }
+
+    /// Returns the bitmask of the set, as described in [`Self::from_bits`].
+    pub const fn to_bits(&self) -> K::Bits {
+        self.bits
+    }
+
+    const fn words(&self) -> &[u64] {
+        // SAFETY: `K::Bits` is laid out as `[u64; W]` since `Bits` is sealed, and `WORDS` is
+        // `W`.
+        unsafe {
+            core::slice::from_raw_parts(&self.bits as *const K::Bits as *const u64, Self::WORDS)
+        }
+    }
+
+    const fn words_mut(&mut self) -> &mut [u64] {
+        // SAFETY: see `words`.
+        unsafe {
+            core::slice::from_raw_parts_mut(&mut self.bits as *mut K::Bits as *mut u64, Self::WORDS)
+        }
+    }
+
+    /// Clears the bits past the last variant, which the set operations may have set.
+    const fn clear_unused(&mut self) {
+        let words = self.words_mut();
+        let mut i = K::COUNT / 64;
+        if K::COUNT % 64 != 0 {
+            words[i] &= (1 << (K::COUNT % 64)) - 1;
+            i += 1;
+        }
+        while i < words.len() {
+            words[i] = 0;
+            i += 1;
+        }
+    }
+
+    /// Sets the bit at `index` to `value`, returning its previous value.
+    pub(crate) const fn replace_bit(&mut self, index: usize, value: bool) -> bool {
+        let words = self.words_mut();
+        let mask = 1 << (index % 64);
+        let previous = words[index / 64] & mask != 0;
+        if value {
+            words[index / 64] |= mask;
+        } else {
+            words[index / 64] &= !mask;
+        }
+        previous
+    }
+
+    /// Returns the bit at `index`.
+    pub(crate) const fn bit(&self, index: usize) -> bool {
+        self.words()[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Adds a variant to the set.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the variant was not in the set.
+    pub fn insert(&mut self, variant: K) -> bool {
+        !self.replace_bit(variant.variant_index(), true)
+    }
+
+    /// Removes a variant from the set.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the variant was in the set.
+    pub fn remove(&mut self, variant: &K) -> bool {
+        self.replace_bit(variant.variant_index(), false)
+    }
+
+    /// Returns `true` if the set contains the variant.
+    ///
+    /// Uses O(1) lookup via [`Enumable::variant_index`](crate::Enumable::variant_index).
+    pub fn contains(&self, variant: &K) -> bool {
+        self.bit(variant.variant_index())
+    }
+
+    /// Adds a variant to the set.
+    ///
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`](crate::Enumable::LAYOUT):
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
+    /// For O(1) access, use [`Self::insert`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if the variant was not in the set.
+    pub const fn insert_const(&mut self, variant: K) -> bool {
+        !self.replace_bit(intrinsics::const_variant_index(&variant), true)
+    }
+
+    /// Removes a variant from the set.
+    ///
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`](crate::Enumable::LAYOUT):
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
+    /// For O(1) access, use [`Self::remove`].
+    ///
+    /// # Returns
+    ///
+    /// `true` if the variant was in the set.
+    pub const fn remove_const(&mut self, variant: &K) -> bool {
+        self.replace_bit(intrinsics::const_variant_index(variant), false)
+    }
+
+    /// Returns `true` if the set contains the variant.
+    ///
+    /// This is a `const fn` that locates the variant through [`Enumable::LAYOUT`](crate::Enumable::LAYOUT):
+    /// in O(1) for dense discriminants, with a binary search for sparse ones, and by
+    /// checking each variant or field in turn for data-carrying enums and structs.
+    /// For O(1) access, use [`Self::contains`].
+    pub const fn contains_const(&self, variant: &K) -> bool {
+        self.bit(intrinsics::const_variant_index(variant))
+    }
+
+    /// Returns the set with `variant` added, for building sets in `const` contexts.
+    pub const fn with(mut self, variant: K) -> Self {
+        self.insert_const(variant);
+        self
+    }
+
+    /// Returns the number of variants in the set.
+    pub const fn len(&self) -> usize {
+        let words = self.words();
+        let mut len = 0;
+        let mut i = 0;
+        while i < words.len() {
+            len += words[i].count_ones() as usize;
+            i += 1;
+        }
+        len
+    }
+
+    /// Returns `true` if the set contains no variants.
+    pub const fn is_empty(&self) -> bool {
+        let words = self.words();
+        let mut i = 0;
+        while i < words.len() {
+            if words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Removes all variants from the set.
+    pub const fn clear(&mut self) {
+        self.bits = K::Bits::EMPTY;
+    }
+
+    /// Returns the set of variants in either `self` or `other`.
+    pub const fn union(mut self, other: Self) -> Self {
+        let other = other.words();
+        let words = self.words_mut();
+        let mut i = 0;
+        while i < words.len() {
+            words[i] |= other[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the set of variants in both `self` and `other`.
+    pub const fn intersection(mut self, other: Self) -> Self {
+        let other = other.words();
+        let words = self.words_mut();
+        let mut i = 0;
+        while i < words.len() {
+            words[i] &= other[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the set of variants in `self` but not in `other`.
+    pub const fn difference(mut self, other: Self) -> Self {
+        let other = other.words();
+        let words = self.words_mut();
+        let mut i = 0;
+        while i < words.len() {
+            words[i] &= !other[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the set of variants in exactly one of `self` and `other`.
+    pub const fn symmetric_difference(mut self, other: Self) -> Self {
+        let other = other.words();
+        let words = self.words_mut();
+        let mut i = 0;
+        while i < words.len() {
+            words[i] ^= other[i];
+            i += 1;
+        }
+        self
+    }
+
+    /// Returns the set of variants not in `self`.
+    pub const fn complement(mut self) -> Self {
+        let words = self.words_mut();
+        let mut i = 0;
+        while i < words.len() {
+            words[i] = !words[i];
+            i += 1;
+        }
+        self.clear_unused();
+        self
+    }
+
+    /// Returns `true` if every variant in `self` is also in `other`.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        let (words, other) = (self.words(), other.words());
+        let mut i = 0;
+        while i < words.len() {
+            if words[i] & !other[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns `true` if every variant in `other` is also in `self`.
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no variants in common.
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        let (words, other) = (self.words(), other.words());
+        let mut i = 0;
+        while i < words.len() {
+            if words[i] & other[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// Returns an iterator over the variants in the set, in the order of
+    /// [`Enumable::VARIANTS`](crate::Enumable::VARIANTS).
+    pub fn iter(&self) -> Iter<K> {
+        Iter {
+            set: *self,
+            word: 0,
+        }
+    }
+}
+
+/// An iterator over the variants of an [`EnumSet`], in the order of [`Enumable::VARIANTS`](crate::Enumable::VARIANTS).
+///
+/// Returned by [`EnumSet::iter`] and [`EnumSet::into_iter`].
+pub struct Iter<K: EnumableTable> {
+    /// The variants not visited yet.
+    set: EnumSet<K>,
+    /// The index of the first word with bits left.
+    word: usize,
+}
+
+impl<K: EnumableTable> Iter<K> {
+    /// Returns the index in [`Enumable::VARIANTS`](crate::Enumable::VARIANTS) of the next
+    /// variant.
+    pub(crate) fn next_index(&mut self) -> Option<usize> {
+        let words = self.set.words_mut();
+        while self.word < words.len() {
+            let word = &mut words[self.word];
+            if *word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+                return Some(self.word * 64 + bit);
+            }
+            self.word += 1;
+        }
+        None
+    }
+}
+
+impl<K: EnumableTable> Iterator for Iter<K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<K> {
+        self.next_index().map(|i| K::VARIANTS[i])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.set.len();
+        (len, Some(len))
+    }
+}
+
+impl<K: EnumableTable> ExactSizeIterator for Iter<K> {}
+
+impl<K: EnumableTable> core::iter::FusedIterator for Iter<K> {}
+
+impl<K: EnumableTable> Clone for Iter<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: EnumableTable> Copy for Iter<K> {}
+
+impl<K: EnumableTable> IntoIterator for EnumSet<K> {
+    type Item = K;
+    type IntoIter = Iter<K>;
+
+    fn into_iter(self) -> Iter<K> {
+        self.iter()
+    }
+}
+
+impl<K: EnumableTable> IntoIterator for &EnumSet<K> {
+    type Item = K;
+    type IntoIter = Iter<K>;
+
+    fn into_iter(self) -> Iter<K> {
+        self.iter()
+    }
+}
+
+impl<K: EnumableTable> FromIterator<K> for EnumSet<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<K: EnumableTable> Extend<K> for EnumSet<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for variant in iter {
+            self.insert(variant);
+        }
+    }
+}
+
+impl<'a, K: EnumableTable> Extend<&'a K> for EnumSet<K> {
+    fn extend<I: IntoIterator<Item = &'a K>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<K: EnumableTable, const N: usize> From<EnumTable<K, bool, N>> for EnumSet<K> {
+    /// Creates a set of the variants whose value is `true`.
+    fn from(table: EnumTable<K, bool, N>) -> Self {
+        let mut set = Self::new();
+        for (i, value) in table.as_slice().iter().enumerate() {
+            set.replace_bit(i, *value);
+        }
+        set
+    }
+}
+
+impl<K: EnumableTable, const N: usize> From<EnumSet<K>> for EnumTable<K, bool, N> {
+    /// Creates a table whose value is `true` for the variants in the set.
+    fn from(set: EnumSet<K>) -> Self {
+        EnumTable::new(core::array::from_fn(|i| set.bit(i)))
+    }
+}
+
+impl<K: EnumableTable + core::fmt::Debug> core::fmt::Debug for EnumSet<K> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<K: EnumableTable> Clone for EnumSet<K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K: EnumableTable> Copy for EnumSet<K> {}
+
+impl<K: EnumableTable> PartialEq for EnumSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.words() == other.words()
+    }
+}
+
+impl<K: EnumableTable> Eq for EnumSet<K> {}
+
+impl<K: EnumableTable> core::hash::Hash for EnumSet<K> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.words().hash(state);
+    }
+}
+
+impl<K: EnumableTable> Default for EnumSet<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: EnumableTable> BitOr for EnumSet<K> {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl<K: EnumableTable> BitAnd for EnumSet<K> {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl<K: EnumableTable> Sub for EnumSet<K> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+impl<K: EnumableTable> BitXor for EnumSet<K> {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        self.symmetric_difference(other)
+    }
+}
+
+impl<K: EnumableTable> Not for EnumSet<K> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Enumable;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    enum Color {
+        Red = 30,
+        Green = 10,
+        Blue = 20,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+    #[enumable(range = 0..130)]
+    struct Pin(u8);
+
+    #[test]
+    fn insert_remove_contains() {
+        let mut set = EnumSet::<Color>::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Color::Red));
+        assert!(!set.insert(Color::Red));
+        assert!(set.insert(Color::Green));
+        assert!(set.contains(&Color::Red));
+        assert!(!set.contains(&Color::Blue));
+        assert_eq!(set.len(), 2);
+
+        assert!(set.remove(&Color::Red));
+        assert!(!set.remove(&Color::Red));
+        assert_eq!(set.len(), 1);
+
+        set.clear();
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn const_accessors() {
+        const SET: EnumSet<Color> = {
+            let mut set = EnumSet::new().with(Color::Blue).with(Color::Red);
+            set.remove_const(&Color::Red);
+            set.insert_const(Color::Green);
+            set
+        };
+        const HAS_BLUE: bool = SET.contains_const(&Color::Blue);
+        const HAS_RED: bool = SET.contains_const(&Color::Red);
+        assert_eq!((HAS_BLUE, HAS_RED), (true, false));
+        assert_eq!(SET.len(), 2);
+    }
+
+    #[test]
+    fn iteration_order() {
+        let set: EnumSet<Color> = [Color::Red, Color::Blue, Color::Green]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [Color::Green, Color::Blue, Color::Red]
+        );
+        assert_eq!(set.iter().len(), 3);
+        assert_eq!(format!("{set:?}"), "{Green, Blue, Red}");
+    }
+
+    #[test]
+    fn set_algebra() {
+        let warm = EnumSet::new().with(Color::Red);
+        let rgb = EnumSet::<Color>::all();
+        let cool = EnumSet::new().with(Color::Green).with(Color::Blue);
+
+        assert_eq!(warm | cool, rgb);
+        assert_eq!(warm & cool, EnumSet::new());
+        assert_eq!(rgb - warm, cool);
+        assert_eq!(rgb ^ cool, warm);
+        assert_eq!(!warm, cool);
+        assert_eq!(!rgb, EnumSet::new());
+        assert!(warm.is_subset(&rgb));
+        assert!(rgb.is_superset(&cool));
+        assert!(warm.is_disjoint(&cool));
+        assert!(!rgb.is_disjoint(&cool));
+    }
+
+    #[test]
+    fn multiple_words() {
+        let all = EnumSet::<Pin>::all();
+        assert_eq!(all.len(), 130);
+        assert_eq!(all.to_bits(), [u64::MAX, u64::MAX, 0b11]);
+        assert_eq!(EnumSet::<Pin>::from_bits([0, 1, u64::MAX]).len(), 3);
+
+        let mut set = EnumSet::new();
+        set.extend([Pin(129), Pin(3), Pin(64)]);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [Pin(3), Pin(64), Pin(129)]);
+        assert_eq!((!set).len(), 127);
+        assert!(!(!set).contains(&Pin(64)));
+    }
+
+    #[test]
+    fn composite_keys() {
+        let all = EnumSet::<Option<Pin>>::all();
+        assert_eq!(all.len(), 131);
+        assert!(all.contains(&None) && all.contains(&Some(Pin(129))));
+        assert_eq!((!EnumSet::<Option<Pin>>::new().with(None)).len(), 130);
+
+        let set: EnumSet<(Color, bool)> = [(Color::Blue, true), (Color::Red, false)]
+            .into_iter()
+            .collect();
+        assert_eq!(
+            set.iter().collect::<Vec<_>>(),
+            [(Color::Blue, true), (Color::Red, false)]
+        );
+        assert_eq!(set.complement().len(), 4);
+        assert_eq!(EnumSet::<(bool, bool, Color)>::all().len(), 12);
+        assert_eq!(EnumSet::<Result<Color, Pin>>::all().len(), 133);
+    }
+
+    #[test]
+    fn table_conversions() {
+        let set = EnumSet::new().with(Color::Blue);
+        let table: EnumTable<Color, bool, { Color::COUNT }> = set.into();
+        assert_eq!(table.as_slice(), &[false, true, false]);
+        assert_eq!(EnumSet::from(table), set);
+    }
+
+    #[test]
+    fn empty_enum() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, crate::Enumable)]
+        enum Empty {}
+
+        let set = EnumSet::<Empty>::all();
+        assert!(set.is_empty());
+        assert_eq!(set.iter().next(), None);
+        assert_eq!(set.to_bits(), [0u64; 0]);
+    }
+}
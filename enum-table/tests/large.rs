@@ -0,0 +1,593 @@
+//! Derives `Enumable` on enums with 4096 variants, to keep the `const` evaluation the
+//! derive relies on (sorting, index tables, per-variant lookups) at O(N log N).
+//!
+//! To measure the compile-time cost, time this target on its own:
+//!
+//! ```sh
+//! touch enum-table/tests/large.rs
+//! CARGO_INCREMENTAL=0 cargo test -p enum-table --test large --no-run --timings
+//! ```
+
+use enum_table::{EnumTable, Enumable};
+
+/// Declares `$name` with the given variants and discriminants, `$declared` with the same
+/// in declaration order, and `$dense` with the same variants numbered from 0.
+macro_rules! large_enums {
+    ($name:ident, $declared:ident, $dense:ident; $($variant:ident = $discriminant:literal,)*) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+        #[repr(u16)]
+        pub enum $name {
+            $($variant = $discriminant,)*
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+        #[enumable(order = "declaration")]
+        #[repr(u16)]
+        pub enum $declared {
+            $($variant = $discriminant,)*
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+        pub enum $dense {
+            $($variant,)*
+        }
+    };
+}
+
+// Discriminants are a permutation of `0, 5, 10, ...`, too sparse for a lookup table.
+large_enums!(Sparse, Declared, Dense;
+    V0000 = 0, V0001 = 6185, V0002 = 12370, V0003 = 18555, V0004 = 4260, V0005 = 10445, V0006 = 16630, V0007 = 2335,
+    V0008 = 8520, V0009 = 14705, V0010 = 410, V0011 = 6595, V0012 = 12780, V0013 = 18965, V0014 = 4670, V0015 = 10855,
+    V0016 = 17040, V0017 = 2745, V0018 = 8930, V0019 = 15115, V0020 = 820, V0021 = 7005, V0022 = 13190, V0023 = 19375,
+    V0024 = 5080, V0025 = 11265, V0026 = 17450, V0027 = 3155, V0028 = 9340, V0029 = 15525, V0030 = 1230, V0031 = 7415,
+    V0032 = 13600, V0033 = 19785, V0034 = 5490, V0035 = 11675, V0036 = 17860, V0037 = 3565, V0038 = 9750, V0039 = 15935,
+    V0040 = 1640, V0041 = 7825, V0042 = 14010, V0043 = 20195, V0044 = 5900, V0045 = 12085, V0046 = 18270, V0047 = 3975,
+    V0048 = 10160, V0049 = 16345, V0050 = 2050, V0051 = 8235, V0052 = 14420, V0053 = 125, V0054 = 6310, V0055 = 12495,
+    V0056 = 18680, V0057 = 4385, V0058 = 10570, V0059 = 16755, V0060 = 2460, V0061 = 8645, V0062 = 14830, V0063 = 535,
+    V0064 = 6720, V0065 = 12905, V0066 = 19090, V0067 = 4795, V0068 = 10980, V0069 = 17165, V0070 = 2870, V0071 = 9055,
+    V0072 = 15240, V0073 = 945, V0074 = 7130, V0075 = 13315, V0076 = 19500, V0077 = 5205, V0078 = 11390, V0079 = 17575,
+    V0080 = 3280, V0081 = 9465, V0082 = 15650, V0083 = 1355, V0084 = 7540, V0085 = 13725, V0086 = 19910, V0087 = 5615,
+    V0088 = 11800, V0089 = 17985, V0090 = 3690, V0091 = 9875, V0092 = 16060, V0093 = 1765, V0094 = 7950, V0095 = 14135,
+    V0096 = 20320, V0097 = 6025, V0098 = 12210, V0099 = 18395, V0100 = 4100, V0101 = 10285, V0102 = 16470, V0103 = 2175,
+    V0104 = 8360, V0105 = 14545, V0106 = 250, V0107 = 6435, V0108 = 12620, V0109 = 18805, V0110 = 4510, V0111 = 10695,
+    V0112 = 16880, V0113 = 2585, V0114 = 8770, V0115 = 14955, V0116 = 660, V0117 = 6845, V0118 = 13030, V0119 = 19215,
+    V0120 = 4920, V0121 = 11105, V0122 = 17290, V0123 = 2995, V0124 = 9180, V0125 = 15365, V0126 = 1070, V0127 = 7255,
+    V0128 = 13440, V0129 = 19625, V0130 = 5330, V0131 = 11515, V0132 = 17700, V0133 = 3405, V0134 = 9590, V0135 = 15775,
+    V0136 = 1480, V0137 = 7665, V0138 = 13850, V0139 = 20035, V0140 = 5740, V0141 = 11925, V0142 = 18110, V0143 = 3815,
+    V0144 = 10000, V0145 = 16185, V0146 = 1890, V0147 = 8075, V0148 = 14260, V0149 = 20445, V0150 = 6150, V0151 = 12335,
+    V0152 = 18520, V0153 = 4225, V0154 = 10410, V0155 = 16595, V0156 = 2300, V0157 = 8485, V0158 = 14670, V0159 = 375,
+    V0160 = 6560, V0161 = 12745, V0162 = 18930, V0163 = 4635, V0164 = 10820, V0165 = 17005, V0166 = 2710, V0167 = 8895,
+    V0168 = 15080, V0169 = 785, V0170 = 6970, V0171 = 13155, V0172 = 19340, V0173 = 5045, V0174 = 11230, V0175 = 17415,
+    V0176 = 3120, V0177 = 9305, V0178 = 15490, V0179 = 1195, V0180 = 7380, V0181 = 13565, V0182 = 19750, V0183 = 5455,
+    V0184 = 11640, V0185 = 17825, V0186 = 3530, V0187 = 9715, V0188 = 15900, V0189 = 1605, V0190 = 7790, V0191 = 13975,
+    V0192 = 20160, V0193 = 5865, V0194 = 12050, V0195 = 18235, V0196 = 3940, V0197 = 10125, V0198 = 16310, V0199 = 2015,
+    V0200 = 8200, V0201 = 14385, V0202 = 90, V0203 = 6275, V0204 = 12460, V0205 = 18645, V0206 = 4350, V0207 = 10535,
+    V0208 = 16720, V0209 = 2425, V0210 = 8610, V0211 = 14795, V0212 = 500, V0213 = 6685, V0214 = 12870, V0215 = 19055,
+    V0216 = 4760, V0217 = 10945, V0218 = 17130, V0219 = 2835, V0220 = 9020, V0221 = 15205, V0222 = 910, V0223 = 7095,
+    V0224 = 13280, V0225 = 19465, V0226 = 5170, V0227 = 11355, V0228 = 17540, V0229 = 3245, V0230 = 9430, V0231 = 15615,
+    V0232 = 1320, V0233 = 7505, V0234 = 13690, V0235 = 19875, V0236 = 5580, V0237 = 11765, V0238 = 17950, V0239 = 3655,
+    V0240 = 9840, V0241 = 16025, V0242 = 1730, V0243 = 7915, V0244 = 14100, V0245 = 20285, V0246 = 5990, V0247 = 12175,
+    V0248 = 18360, V0249 = 4065, V0250 = 10250, V0251 = 16435, V0252 = 2140, V0253 = 8325, V0254 = 14510, V0255 = 215,
+    V0256 = 6400, V0257 = 12585, V0258 = 18770, V0259 = 4475, V0260 = 10660, V0261 = 16845, V0262 = 2550, V0263 = 8735,
+    V0264 = 14920, V0265 = 625, V0266 = 6810, V0267 = 12995, V0268 = 19180, V0269 = 4885, V0270 = 11070, V0271 = 17255,
+    V0272 = 2960, V0273 = 9145, V0274 = 15330, V0275 = 1035, V0276 = 7220, V0277 = 13405, V0278 = 19590, V0279 = 5295,
+    V0280 = 11480, V0281 = 17665, V0282 = 3370, V0283 = 9555, V0284 = 15740, V0285 = 1445, V0286 = 7630, V0287 = 13815,
+    V0288 = 20000, V0289 = 5705, V0290 = 11890, V0291 = 18075, V0292 = 3780, V0293 = 9965, V0294 = 16150, V0295 = 1855,
+    V0296 = 8040, V0297 = 14225, V0298 = 20410, V0299 = 6115, V0300 = 12300, V0301 = 18485, V0302 = 4190, V0303 = 10375,
+    V0304 = 16560, V0305 = 2265, V0306 = 8450, V0307 = 14635, V0308 = 340, V0309 = 6525, V0310 = 12710, V0311 = 18895,
+    V0312 = 4600, V0313 = 10785, V0314 = 16970, V0315 = 2675, V0316 = 8860, V0317 = 15045, V0318 = 750, V0319 = 6935,
+    V0320 = 13120, V0321 = 19305, V0322 = 5010, V0323 = 11195, V0324 = 17380, V0325 = 3085, V0326 = 9270, V0327 = 15455,
+    V0328 = 1160, V0329 = 7345, V0330 = 13530, V0331 = 19715, V0332 = 5420, V0333 = 11605, V0334 = 17790, V0335 = 3495,
+    V0336 = 9680, V0337 = 15865, V0338 = 1570, V0339 = 7755, V0340 = 13940, V0341 = 20125, V0342 = 5830, V0343 = 12015,
+    V0344 = 18200, V0345 = 3905, V0346 = 10090, V0347 = 16275, V0348 = 1980, V0349 = 8165, V0350 = 14350, V0351 = 55,
+    V0352 = 6240, V0353 = 12425, V0354 = 18610, V0355 = 4315, V0356 = 10500, V0357 = 16685, V0358 = 2390, V0359 = 8575,
+    V0360 = 14760, V0361 = 465, V0362 = 6650, V0363 = 12835, V0364 = 19020, V0365 = 4725, V0366 = 10910, V0367 = 17095,
+    V0368 = 2800, V0369 = 8985, V0370 = 15170, V0371 = 875, V0372 = 7060, V0373 = 13245, V0374 = 19430, V0375 = 5135,
+    V0376 = 11320, V0377 = 17505, V0378 = 3210, V0379 = 9395, V0380 = 15580, V0381 = 1285, V0382 = 7470, V0383 = 13655,
+    V0384 = 19840, V0385 = 5545, V0386 = 11730, V0387 = 17915, V0388 = 3620, V0389 = 9805, V0390 = 15990, V0391 = 1695,
+    V0392 = 7880, V0393 = 14065, V0394 = 20250, V0395 = 5955, V0396 = 12140, V0397 = 18325, V0398 = 4030, V0399 = 10215,
+    V0400 = 16400, V0401 = 2105, V0402 = 8290, V0403 = 14475, V0404 = 180, V0405 = 6365, V0406 = 12550, V0407 = 18735,
+    V0408 = 4440, V0409 = 10625, V0410 = 16810, V0411 = 2515, V0412 = 8700, V0413 = 14885, V0414 = 590, V0415 = 6775,
+    V0416 = 12960, V0417 = 19145, V0418 = 4850, V0419 = 11035, V0420 = 17220, V0421 = 2925, V0422 = 9110, V0423 = 15295,
+    V0424 = 1000, V0425 = 7185, V0426 = 13370, V0427 = 19555, V0428 = 5260, V0429 = 11445, V0430 = 17630, V0431 = 3335,
+    V0432 = 9520, V0433 = 15705, V0434 = 1410, V0435 = 7595, V0436 = 13780, V0437 = 19965, V0438 = 5670, V0439 = 11855,
+    V0440 = 18040, V0441 = 3745, V0442 = 9930, V0443 = 16115, V0444 = 1820, V0445 = 8005, V0446 = 14190, V0447 = 20375,
+    V0448 = 6080, V0449 = 12265, V0450 = 18450, V0451 = 4155, V0452 = 10340, V0453 = 16525, V0454 = 2230, V0455 = 8415,
+    V0456 = 14600, V0457 = 305, V0458 = 6490, V0459 = 12675, V0460 = 18860, V0461 = 4565, V0462 = 10750, V0463 = 16935,
+    V0464 = 2640, V0465 = 8825, V0466 = 15010, V0467 = 715, V0468 = 6900, V0469 = 13085, V0470 = 19270, V0471 = 4975,
+    V0472 = 11160, V0473 = 17345, V0474 = 3050, V0475 = 9235, V0476 = 15420, V0477 = 1125, V0478 = 7310, V0479 = 13495,
+    V0480 = 19680, V0481 = 5385, V0482 = 11570, V0483 = 17755, V0484 = 3460, V0485 = 9645, V0486 = 15830, V0487 = 1535,
+    V0488 = 7720, V0489 = 13905, V0490 = 20090, V0491 = 5795, V0492 = 11980, V0493 = 18165, V0494 = 3870, V0495 = 10055,
+    V0496 = 16240, V0497 = 1945, V0498 = 8130, V0499 = 14315, V0500 = 20, V0501 = 6205, V0502 = 12390, V0503 = 18575,
+    V0504 = 4280, V0505 = 10465, V0506 = 16650, V0507 = 2355, V0508 = 8540, V0509 = 14725, V0510 = 430, V0511 = 6615,
+    V0512 = 12800, V0513 = 18985, V0514 = 4690, V0515 = 10875, V0516 = 17060, V0517 = 2765, V0518 = 8950, V0519 = 15135,
+    V0520 = 840, V0521 = 7025, V0522 = 13210, V0523 = 19395, V0524 = 5100, V0525 = 11285, V0526 = 17470, V0527 = 3175,
+    V0528 = 9360, V0529 = 15545, V0530 = 1250, V0531 = 7435, V0532 = 13620, V0533 = 19805, V0534 = 5510, V0535 = 11695,
+    V0536 = 17880, V0537 = 3585, V0538 = 9770, V0539 = 15955, V0540 = 1660, V0541 = 7845, V0542 = 14030, V0543 = 20215,
+    V0544 = 5920, V0545 = 12105, V0546 = 18290, V0547 = 3995, V0548 = 10180, V0549 = 16365, V0550 = 2070, V0551 = 8255,
+    V0552 = 14440, V0553 = 145, V0554 = 6330, V0555 = 12515, V0556 = 18700, V0557 = 4405, V0558 = 10590, V0559 = 16775,
+    V0560 = 2480, V0561 = 8665, V0562 = 14850, V0563 = 555, V0564 = 6740, V0565 = 12925, V0566 = 19110, V0567 = 4815,
+    V0568 = 11000, V0569 = 17185, V0570 = 2890, V0571 = 9075, V0572 = 15260, V0573 = 965, V0574 = 7150, V0575 = 13335,
+    V0576 = 19520, V0577 = 5225, V0578 = 11410, V0579 = 17595, V0580 = 3300, V0581 = 9485, V0582 = 15670, V0583 = 1375,
+    V0584 = 7560, V0585 = 13745, V0586 = 19930, V0587 = 5635, V0588 = 11820, V0589 = 18005, V0590 = 3710, V0591 = 9895,
+    V0592 = 16080, V0593 = 1785, V0594 = 7970, V0595 = 14155, V0596 = 20340, V0597 = 6045, V0598 = 12230, V0599 = 18415,
+    V0600 = 4120, V0601 = 10305, V0602 = 16490, V0603 = 2195, V0604 = 8380, V0605 = 14565, V0606 = 270, V0607 = 6455,
+    V0608 = 12640, V0609 = 18825, V0610 = 4530, V0611 = 10715, V0612 = 16900, V0613 = 2605, V0614 = 8790, V0615 = 14975,
+    V0616 = 680, V0617 = 6865, V0618 = 13050, V0619 = 19235, V0620 = 4940, V0621 = 11125, V0622 = 17310, V0623 = 3015,
+    V0624 = 9200, V0625 = 15385, V0626 = 1090, V0627 = 7275, V0628 = 13460, V0629 = 19645, V0630 = 5350, V0631 = 11535,
+    V0632 = 17720, V0633 = 3425, V0634 = 9610, V0635 = 15795, V0636 = 1500, V0637 = 7685, V0638 = 13870, V0639 = 20055,
+    V0640 = 5760, V0641 = 11945, V0642 = 18130, V0643 = 3835, V0644 = 10020, V0645 = 16205, V0646 = 1910, V0647 = 8095,
+    V0648 = 14280, V0649 = 20465, V0650 = 6170, V0651 = 12355, V0652 = 18540, V0653 = 4245, V0654 = 10430, V0655 = 16615,
+    V0656 = 2320, V0657 = 8505, V0658 = 14690, V0659 = 395, V0660 = 6580, V0661 = 12765, V0662 = 18950, V0663 = 4655,
+    V0664 = 10840, V0665 = 17025, V0666 = 2730, V0667 = 8915, V0668 = 15100, V0669 = 805, V0670 = 6990, V0671 = 13175,
+    V0672 = 19360, V0673 = 5065, V0674 = 11250, V0675 = 17435, V0676 = 3140, V0677 = 9325, V0678 = 15510, V0679 = 1215,
+    V0680 = 7400, V0681 = 13585, V0682 = 19770, V0683 = 5475, V0684 = 11660, V0685 = 17845, V0686 = 3550, V0687 = 9735,
+    V0688 = 15920, V0689 = 1625, V0690 = 7810, V0691 = 13995, V0692 = 20180, V0693 = 5885, V0694 = 12070, V0695 = 18255,
+    V0696 = 3960, V0697 = 10145, V0698 = 16330, V0699 = 2035, V0700 = 8220, V0701 = 14405, V0702 = 110, V0703 = 6295,
+    V0704 = 12480, V0705 = 18665, V0706 = 4370, V0707 = 10555, V0708 = 16740, V0709 = 2445, V0710 = 8630, V0711 = 14815,
+    V0712 = 520, V0713 = 6705, V0714 = 12890, V0715 = 19075, V0716 = 4780, V0717 = 10965, V0718 = 17150, V0719 = 2855,
+    V0720 = 9040, V0721 = 15225, V0722 = 930, V0723 = 7115, V0724 = 13300, V0725 = 19485, V0726 = 5190, V0727 = 11375,
+    V0728 = 17560, V0729 = 3265, V0730 = 9450, V0731 = 15635, V0732 = 1340, V0733 = 7525, V0734 = 13710, V0735 = 19895,
+    V0736 = 5600, V0737 = 11785, V0738 = 17970, V0739 = 3675, V0740 = 9860, V0741 = 16045, V0742 = 1750, V0743 = 7935,
+    V0744 = 14120, V0745 = 20305, V0746 = 6010, V0747 = 12195, V0748 = 18380, V0749 = 4085, V0750 = 10270, V0751 = 16455,
+    V0752 = 2160, V0753 = 8345, V0754 = 14530, V0755 = 235, V0756 = 6420, V0757 = 12605, V0758 = 18790, V0759 = 4495,
+    V0760 = 10680, V0761 = 16865, V0762 = 2570, V0763 = 8755, V0764 = 14940, V0765 = 645, V0766 = 6830, V0767 = 13015,
+    V0768 = 19200, V0769 = 4905, V0770 = 11090, V0771 = 17275, V0772 = 2980, V0773 = 9165, V0774 = 15350, V0775 = 1055,
+    V0776 = 7240, V0777 = 13425, V0778 = 19610, V0779 = 5315, V0780 = 11500, V0781 = 17685, V0782 = 3390, V0783 = 9575,
+    V0784 = 15760, V0785 = 1465, V0786 = 7650, V0787 = 13835, V0788 = 20020, V0789 = 5725, V0790 = 11910, V0791 = 18095,
+    V0792 = 3800, V0793 = 9985, V0794 = 16170, V0795 = 1875, V0796 = 8060, V0797 = 14245, V0798 = 20430, V0799 = 6135,
+    V0800 = 12320, V0801 = 18505, V0802 = 4210, V0803 = 10395, V0804 = 16580, V0805 = 2285, V0806 = 8470, V0807 = 14655,
+    V0808 = 360, V0809 = 6545, V0810 = 12730, V0811 = 18915, V0812 = 4620, V0813 = 10805, V0814 = 16990, V0815 = 2695,
+    V0816 = 8880, V0817 = 15065, V0818 = 770, V0819 = 6955, V0820 = 13140, V0821 = 19325, V0822 = 5030, V0823 = 11215,
+    V0824 = 17400, V0825 = 3105, V0826 = 9290, V0827 = 15475, V0828 = 1180, V0829 = 7365, V0830 = 13550, V0831 = 19735,
+    V0832 = 5440, V0833 = 11625, V0834 = 17810, V0835 = 3515, V0836 = 9700, V0837 = 15885, V0838 = 1590, V0839 = 7775,
+    V0840 = 13960, V0841 = 20145, V0842 = 5850, V0843 = 12035, V0844 = 18220, V0845 = 3925, V0846 = 10110, V0847 = 16295,
+    V0848 = 2000, V0849 = 8185, V0850 = 14370, V0851 = 75, V0852 = 6260, V0853 = 12445, V0854 = 18630, V0855 = 4335,
+    V0856 = 10520, V0857 = 16705, V0858 = 2410, V0859 = 8595, V0860 = 14780, V0861 = 485, V0862 = 6670, V0863 = 12855,
+    V0864 = 19040, V0865 = 4745, V0866 = 10930, V0867 = 17115, V0868 = 2820, V0869 = 9005, V0870 = 15190, V0871 = 895,
+    V0872 = 7080, V0873 = 13265, V0874 = 19450, V0875 = 5155, V0876 = 11340, V0877 = 17525, V0878 = 3230, V0879 = 9415,
+    V0880 = 15600, V0881 = 1305, V0882 = 7490, V0883 = 13675, V0884 = 19860, V0885 = 5565, V0886 = 11750, V0887 = 17935,
+    V0888 = 3640, V0889 = 9825, V0890 = 16010, V0891 = 1715, V0892 = 7900, V0893 = 14085, V0894 = 20270, V0895 = 5975,
+    V0896 = 12160, V0897 = 18345, V0898 = 4050, V0899 = 10235, V0900 = 16420, V0901 = 2125, V0902 = 8310, V0903 = 14495,
+    V0904 = 200, V0905 = 6385, V0906 = 12570, V0907 = 18755, V0908 = 4460, V0909 = 10645, V0910 = 16830, V0911 = 2535,
+    V0912 = 8720, V0913 = 14905, V0914 = 610, V0915 = 6795, V0916 = 12980, V0917 = 19165, V0918 = 4870, V0919 = 11055,
+    V0920 = 17240, V0921 = 2945, V0922 = 9130, V0923 = 15315, V0924 = 1020, V0925 = 7205, V0926 = 13390, V0927 = 19575,
+    V0928 = 5280, V0929 = 11465, V0930 = 17650, V0931 = 3355, V0932 = 9540, V0933 = 15725, V0934 = 1430, V0935 = 7615,
+    V0936 = 13800, V0937 = 19985, V0938 = 5690, V0939 = 11875, V0940 = 18060, V0941 = 3765, V0942 = 9950, V0943 = 16135,
+    V0944 = 1840, V0945 = 8025, V0946 = 14210, V0947 = 20395, V0948 = 6100, V0949 = 12285, V0950 = 18470, V0951 = 4175,
+    V0952 = 10360, V0953 = 16545, V0954 = 2250, V0955 = 8435, V0956 = 14620, V0957 = 325, V0958 = 6510, V0959 = 12695,
+    V0960 = 18880, V0961 = 4585, V0962 = 10770, V0963 = 16955, V0964 = 2660, V0965 = 8845, V0966 = 15030, V0967 = 735,
+    V0968 = 6920, V0969 = 13105, V0970 = 19290, V0971 = 4995, V0972 = 11180, V0973 = 17365, V0974 = 3070, V0975 = 9255,
+    V0976 = 15440, V0977 = 1145, V0978 = 7330, V0979 = 13515, V0980 = 19700, V0981 = 5405, V0982 = 11590, V0983 = 17775,
+    V0984 = 3480, V0985 = 9665, V0986 = 15850, V0987 = 1555, V0988 = 7740, V0989 = 13925, V0990 = 20110, V0991 = 5815,
+    V0992 = 12000, V0993 = 18185, V0994 = 3890, V0995 = 10075, V0996 = 16260, V0997 = 1965, V0998 = 8150, V0999 = 14335,
+    V1000 = 40, V1001 = 6225, V1002 = 12410, V1003 = 18595, V1004 = 4300, V1005 = 10485, V1006 = 16670, V1007 = 2375,
+    V1008 = 8560, V1009 = 14745, V1010 = 450, V1011 = 6635, V1012 = 12820, V1013 = 19005, V1014 = 4710, V1015 = 10895,
+    V1016 = 17080, V1017 = 2785, V1018 = 8970, V1019 = 15155, V1020 = 860, V1021 = 7045, V1022 = 13230, V1023 = 19415,
+    V1024 = 5120, V1025 = 11305, V1026 = 17490, V1027 = 3195, V1028 = 9380, V1029 = 15565, V1030 = 1270, V1031 = 7455,
+    V1032 = 13640, V1033 = 19825, V1034 = 5530, V1035 = 11715, V1036 = 17900, V1037 = 3605, V1038 = 9790, V1039 = 15975,
+    V1040 = 1680, V1041 = 7865, V1042 = 14050, V1043 = 20235, V1044 = 5940, V1045 = 12125, V1046 = 18310, V1047 = 4015,
+    V1048 = 10200, V1049 = 16385, V1050 = 2090, V1051 = 8275, V1052 = 14460, V1053 = 165, V1054 = 6350, V1055 = 12535,
+    V1056 = 18720, V1057 = 4425, V1058 = 10610, V1059 = 16795, V1060 = 2500, V1061 = 8685, V1062 = 14870, V1063 = 575,
+    V1064 = 6760, V1065 = 12945, V1066 = 19130, V1067 = 4835, V1068 = 11020, V1069 = 17205, V1070 = 2910, V1071 = 9095,
+    V1072 = 15280, V1073 = 985, V1074 = 7170, V1075 = 13355, V1076 = 19540, V1077 = 5245, V1078 = 11430, V1079 = 17615,
+    V1080 = 3320, V1081 = 9505, V1082 = 15690, V1083 = 1395, V1084 = 7580, V1085 = 13765, V1086 = 19950, V1087 = 5655,
+    V1088 = 11840, V1089 = 18025, V1090 = 3730, V1091 = 9915, V1092 = 16100, V1093 = 1805, V1094 = 7990, V1095 = 14175,
+    V1096 = 20360, V1097 = 6065, V1098 = 12250, V1099 = 18435, V1100 = 4140, V1101 = 10325, V1102 = 16510, V1103 = 2215,
+    V1104 = 8400, V1105 = 14585, V1106 = 290, V1107 = 6475, V1108 = 12660, V1109 = 18845, V1110 = 4550, V1111 = 10735,
+    V1112 = 16920, V1113 = 2625, V1114 = 8810, V1115 = 14995, V1116 = 700, V1117 = 6885, V1118 = 13070, V1119 = 19255,
+    V1120 = 4960, V1121 = 11145, V1122 = 17330, V1123 = 3035, V1124 = 9220, V1125 = 15405, V1126 = 1110, V1127 = 7295,
+    V1128 = 13480, V1129 = 19665, V1130 = 5370, V1131 = 11555, V1132 = 17740, V1133 = 3445, V1134 = 9630, V1135 = 15815,
+    V1136 = 1520, V1137 = 7705, V1138 = 13890, V1139 = 20075, V1140 = 5780, V1141 = 11965, V1142 = 18150, V1143 = 3855,
+    V1144 = 10040, V1145 = 16225, V1146 = 1930, V1147 = 8115, V1148 = 14300, V1149 = 5, V1150 = 6190, V1151 = 12375,
+    V1152 = 18560, V1153 = 4265, V1154 = 10450, V1155 = 16635, V1156 = 2340, V1157 = 8525, V1158 = 14710, V1159 = 415,
+    V1160 = 6600, V1161 = 12785, V1162 = 18970, V1163 = 4675, V1164 = 10860, V1165 = 17045, V1166 = 2750, V1167 = 8935,
+    V1168 = 15120, V1169 = 825, V1170 = 7010, V1171 = 13195, V1172 = 19380, V1173 = 5085, V1174 = 11270, V1175 = 17455,
+    V1176 = 3160, V1177 = 9345, V1178 = 15530, V1179 = 1235, V1180 = 7420, V1181 = 13605, V1182 = 19790, V1183 = 5495,
+    V1184 = 11680, V1185 = 17865, V1186 = 3570, V1187 = 9755, V1188 = 15940, V1189 = 1645, V1190 = 7830, V1191 = 14015,
+    V1192 = 20200, V1193 = 5905, V1194 = 12090, V1195 = 18275, V1196 = 3980, V1197 = 10165, V1198 = 16350, V1199 = 2055,
+    V1200 = 8240, V1201 = 14425, V1202 = 130, V1203 = 6315, V1204 = 12500, V1205 = 18685, V1206 = 4390, V1207 = 10575,
+    V1208 = 16760, V1209 = 2465, V1210 = 8650, V1211 = 14835, V1212 = 540, V1213 = 6725, V1214 = 12910, V1215 = 19095,
+    V1216 = 4800, V1217 = 10985, V1218 = 17170, V1219 = 2875, V1220 = 9060, V1221 = 15245, V1222 = 950, V1223 = 7135,
+    V1224 = 13320, V1225 = 19505, V1226 = 5210, V1227 = 11395, V1228 = 17580, V1229 = 3285, V1230 = 9470, V1231 = 15655,
+    V1232 = 1360, V1233 = 7545, V1234 = 13730, V1235 = 19915, V1236 = 5620, V1237 = 11805, V1238 = 17990, V1239 = 3695,
+    V1240 = 9880, V1241 = 16065, V1242 = 1770, V1243 = 7955, V1244 = 14140, V1245 = 20325, V1246 = 6030, V1247 = 12215,
+    V1248 = 18400, V1249 = 4105, V1250 = 10290, V1251 = 16475, V1252 = 2180, V1253 = 8365, V1254 = 14550, V1255 = 255,
+    V1256 = 6440, V1257 = 12625, V1258 = 18810, V1259 = 4515, V1260 = 10700, V1261 = 16885, V1262 = 2590, V1263 = 8775,
+    V1264 = 14960, V1265 = 665, V1266 = 6850, V1267 = 13035, V1268 = 19220, V1269 = 4925, V1270 = 11110, V1271 = 17295,
+    V1272 = 3000, V1273 = 9185, V1274 = 15370, V1275 = 1075, V1276 = 7260, V1277 = 13445, V1278 = 19630, V1279 = 5335,
+    V1280 = 11520, V1281 = 17705, V1282 = 3410, V1283 = 9595, V1284 = 15780, V1285 = 1485, V1286 = 7670, V1287 = 13855,
+    V1288 = 20040, V1289 = 5745, V1290 = 11930, V1291 = 18115, V1292 = 3820, V1293 = 10005, V1294 = 16190, V1295 = 1895,
+    V1296 = 8080, V1297 = 14265, V1298 = 20450, V1299 = 6155, V1300 = 12340, V1301 = 18525, V1302 = 4230, V1303 = 10415,
+    V1304 = 16600, V1305 = 2305, V1306 = 8490, V1307 = 14675, V1308 = 380, V1309 = 6565, V1310 = 12750, V1311 = 18935,
+    V1312 = 4640, V1313 = 10825, V1314 = 17010, V1315 = 2715, V1316 = 8900, V1317 = 15085, V1318 = 790, V1319 = 6975,
+    V1320 = 13160, V1321 = 19345, V1322 = 5050, V1323 = 11235, V1324 = 17420, V1325 = 3125, V1326 = 9310, V1327 = 15495,
+    V1328 = 1200, V1329 = 7385, V1330 = 13570, V1331 = 19755, V1332 = 5460, V1333 = 11645, V1334 = 17830, V1335 = 3535,
+    V1336 = 9720, V1337 = 15905, V1338 = 1610, V1339 = 7795, V1340 = 13980, V1341 = 20165, V1342 = 5870, V1343 = 12055,
+    V1344 = 18240, V1345 = 3945, V1346 = 10130, V1347 = 16315, V1348 = 2020, V1349 = 8205, V1350 = 14390, V1351 = 95,
+    V1352 = 6280, V1353 = 12465, V1354 = 18650, V1355 = 4355, V1356 = 10540, V1357 = 16725, V1358 = 2430, V1359 = 8615,
+    V1360 = 14800, V1361 = 505, V1362 = 6690, V1363 = 12875, V1364 = 19060, V1365 = 4765, V1366 = 10950, V1367 = 17135,
+    V1368 = 2840, V1369 = 9025, V1370 = 15210, V1371 = 915, V1372 = 7100, V1373 = 13285, V1374 = 19470, V1375 = 5175,
+    V1376 = 11360, V1377 = 17545, V1378 = 3250, V1379 = 9435, V1380 = 15620, V1381 = 1325, V1382 = 7510, V1383 = 13695,
+    V1384 = 19880, V1385 = 5585, V1386 = 11770, V1387 = 17955, V1388 = 3660, V1389 = 9845, V1390 = 16030, V1391 = 1735,
+    V1392 = 7920, V1393 = 14105, V1394 = 20290, V1395 = 5995, V1396 = 12180, V1397 = 18365, V1398 = 4070, V1399 = 10255,
+    V1400 = 16440, V1401 = 2145, V1402 = 8330, V1403 = 14515, V1404 = 220, V1405 = 6405, V1406 = 12590, V1407 = 18775,
+    V1408 = 4480, V1409 = 10665, V1410 = 16850, V1411 = 2555, V1412 = 8740, V1413 = 14925, V1414 = 630, V1415 = 6815,
+    V1416 = 13000, V1417 = 19185, V1418 = 4890, V1419 = 11075, V1420 = 17260, V1421 = 2965, V1422 = 9150, V1423 = 15335,
+    V1424 = 1040, V1425 = 7225, V1426 = 13410, V1427 = 19595, V1428 = 5300, V1429 = 11485, V1430 = 17670, V1431 = 3375,
+    V1432 = 9560, V1433 = 15745, V1434 = 1450, V1435 = 7635, V1436 = 13820, V1437 = 20005, V1438 = 5710, V1439 = 11895,
+    V1440 = 18080, V1441 = 3785, V1442 = 9970, V1443 = 16155, V1444 = 1860, V1445 = 8045, V1446 = 14230, V1447 = 20415,
+    V1448 = 6120, V1449 = 12305, V1450 = 18490, V1451 = 4195, V1452 = 10380, V1453 = 16565, V1454 = 2270, V1455 = 8455,
+    V1456 = 14640, V1457 = 345, V1458 = 6530, V1459 = 12715, V1460 = 18900, V1461 = 4605, V1462 = 10790, V1463 = 16975,
+    V1464 = 2680, V1465 = 8865, V1466 = 15050, V1467 = 755, V1468 = 6940, V1469 = 13125, V1470 = 19310, V1471 = 5015,
+    V1472 = 11200, V1473 = 17385, V1474 = 3090, V1475 = 9275, V1476 = 15460, V1477 = 1165, V1478 = 7350, V1479 = 13535,
+    V1480 = 19720, V1481 = 5425, V1482 = 11610, V1483 = 17795, V1484 = 3500, V1485 = 9685, V1486 = 15870, V1487 = 1575,
+    V1488 = 7760, V1489 = 13945, V1490 = 20130, V1491 = 5835, V1492 = 12020, V1493 = 18205, V1494 = 3910, V1495 = 10095,
+    V1496 = 16280, V1497 = 1985, V1498 = 8170, V1499 = 14355, V1500 = 60, V1501 = 6245, V1502 = 12430, V1503 = 18615,
+    V1504 = 4320, V1505 = 10505, V1506 = 16690, V1507 = 2395, V1508 = 8580, V1509 = 14765, V1510 = 470, V1511 = 6655,
+    V1512 = 12840, V1513 = 19025, V1514 = 4730, V1515 = 10915, V1516 = 17100, V1517 = 2805, V1518 = 8990, V1519 = 15175,
+    V1520 = 880, V1521 = 7065, V1522 = 13250, V1523 = 19435, V1524 = 5140, V1525 = 11325, V1526 = 17510, V1527 = 3215,
+    V1528 = 9400, V1529 = 15585, V1530 = 1290, V1531 = 7475, V1532 = 13660, V1533 = 19845, V1534 = 5550, V1535 = 11735,
+    V1536 = 17920, V1537 = 3625, V1538 = 9810, V1539 = 15995, V1540 = 1700, V1541 = 7885, V1542 = 14070, V1543 = 20255,
+    V1544 = 5960, V1545 = 12145, V1546 = 18330, V1547 = 4035, V1548 = 10220, V1549 = 16405, V1550 = 2110, V1551 = 8295,
+    V1552 = 14480, V1553 = 185, V1554 = 6370, V1555 = 12555, V1556 = 18740, V1557 = 4445, V1558 = 10630, V1559 = 16815,
+    V1560 = 2520, V1561 = 8705, V1562 = 14890, V1563 = 595, V1564 = 6780, V1565 = 12965, V1566 = 19150, V1567 = 4855,
+    V1568 = 11040, V1569 = 17225, V1570 = 2930, V1571 = 9115, V1572 = 15300, V1573 = 1005, V1574 = 7190, V1575 = 13375,
+    V1576 = 19560, V1577 = 5265, V1578 = 11450, V1579 = 17635, V1580 = 3340, V1581 = 9525, V1582 = 15710, V1583 = 1415,
+    V1584 = 7600, V1585 = 13785, V1586 = 19970, V1587 = 5675, V1588 = 11860, V1589 = 18045, V1590 = 3750, V1591 = 9935,
+    V1592 = 16120, V1593 = 1825, V1594 = 8010, V1595 = 14195, V1596 = 20380, V1597 = 6085, V1598 = 12270, V1599 = 18455,
+    V1600 = 4160, V1601 = 10345, V1602 = 16530, V1603 = 2235, V1604 = 8420, V1605 = 14605, V1606 = 310, V1607 = 6495,
+    V1608 = 12680, V1609 = 18865, V1610 = 4570, V1611 = 10755, V1612 = 16940, V1613 = 2645, V1614 = 8830, V1615 = 15015,
+    V1616 = 720, V1617 = 6905, V1618 = 13090, V1619 = 19275, V1620 = 4980, V1621 = 11165, V1622 = 17350, V1623 = 3055,
+    V1624 = 9240, V1625 = 15425, V1626 = 1130, V1627 = 7315, V1628 = 13500, V1629 = 19685, V1630 = 5390, V1631 = 11575,
+    V1632 = 17760, V1633 = 3465, V1634 = 9650, V1635 = 15835, V1636 = 1540, V1637 = 7725, V1638 = 13910, V1639 = 20095,
+    V1640 = 5800, V1641 = 11985, V1642 = 18170, V1643 = 3875, V1644 = 10060, V1645 = 16245, V1646 = 1950, V1647 = 8135,
+    V1648 = 14320, V1649 = 25, V1650 = 6210, V1651 = 12395, V1652 = 18580, V1653 = 4285, V1654 = 10470, V1655 = 16655,
+    V1656 = 2360, V1657 = 8545, V1658 = 14730, V1659 = 435, V1660 = 6620, V1661 = 12805, V1662 = 18990, V1663 = 4695,
+    V1664 = 10880, V1665 = 17065, V1666 = 2770, V1667 = 8955, V1668 = 15140, V1669 = 845, V1670 = 7030, V1671 = 13215,
+    V1672 = 19400, V1673 = 5105, V1674 = 11290, V1675 = 17475, V1676 = 3180, V1677 = 9365, V1678 = 15550, V1679 = 1255,
+    V1680 = 7440, V1681 = 13625, V1682 = 19810, V1683 = 5515, V1684 = 11700, V1685 = 17885, V1686 = 3590, V1687 = 9775,
+    V1688 = 15960, V1689 = 1665, V1690 = 7850, V1691 = 14035, V1692 = 20220, V1693 = 5925, V1694 = 12110, V1695 = 18295,
+    V1696 = 4000, V1697 = 10185, V1698 = 16370, V1699 = 2075, V1700 = 8260, V1701 = 14445, V1702 = 150, V1703 = 6335,
+    V1704 = 12520, V1705 = 18705, V1706 = 4410, V1707 = 10595, V1708 = 16780, V1709 = 2485, V1710 = 8670, V1711 = 14855,
+    V1712 = 560, V1713 = 6745, V1714 = 12930, V1715 = 19115, V1716 = 4820, V1717 = 11005, V1718 = 17190, V1719 = 2895,
+    V1720 = 9080, V1721 = 15265, V1722 = 970, V1723 = 7155, V1724 = 13340, V1725 = 19525, V1726 = 5230, V1727 = 11415,
+    V1728 = 17600, V1729 = 3305, V1730 = 9490, V1731 = 15675, V1732 = 1380, V1733 = 7565, V1734 = 13750, V1735 = 19935,
+    V1736 = 5640, V1737 = 11825, V1738 = 18010, V1739 = 3715, V1740 = 9900, V1741 = 16085, V1742 = 1790, V1743 = 7975,
+    V1744 = 14160, V1745 = 20345, V1746 = 6050, V1747 = 12235, V1748 = 18420, V1749 = 4125, V1750 = 10310, V1751 = 16495,
+    V1752 = 2200, V1753 = 8385, V1754 = 14570, V1755 = 275, V1756 = 6460, V1757 = 12645, V1758 = 18830, V1759 = 4535,
+    V1760 = 10720, V1761 = 16905, V1762 = 2610, V1763 = 8795, V1764 = 14980, V1765 = 685, V1766 = 6870, V1767 = 13055,
+    V1768 = 19240, V1769 = 4945, V1770 = 11130, V1771 = 17315, V1772 = 3020, V1773 = 9205, V1774 = 15390, V1775 = 1095,
+    V1776 = 7280, V1777 = 13465, V1778 = 19650, V1779 = 5355, V1780 = 11540, V1781 = 17725, V1782 = 3430, V1783 = 9615,
+    V1784 = 15800, V1785 = 1505, V1786 = 7690, V1787 = 13875, V1788 = 20060, V1789 = 5765, V1790 = 11950, V1791 = 18135,
+    V1792 = 3840, V1793 = 10025, V1794 = 16210, V1795 = 1915, V1796 = 8100, V1797 = 14285, V1798 = 20470, V1799 = 6175,
+    V1800 = 12360, V1801 = 18545, V1802 = 4250, V1803 = 10435, V1804 = 16620, V1805 = 2325, V1806 = 8510, V1807 = 14695,
+    V1808 = 400, V1809 = 6585, V1810 = 12770, V1811 = 18955, V1812 = 4660, V1813 = 10845, V1814 = 17030, V1815 = 2735,
+    V1816 = 8920, V1817 = 15105, V1818 = 810, V1819 = 6995, V1820 = 13180, V1821 = 19365, V1822 = 5070, V1823 = 11255,
+    V1824 = 17440, V1825 = 3145, V1826 = 9330, V1827 = 15515, V1828 = 1220, V1829 = 7405, V1830 = 13590, V1831 = 19775,
+    V1832 = 5480, V1833 = 11665, V1834 = 17850, V1835 = 3555, V1836 = 9740, V1837 = 15925, V1838 = 1630, V1839 = 7815,
+    V1840 = 14000, V1841 = 20185, V1842 = 5890, V1843 = 12075, V1844 = 18260, V1845 = 3965, V1846 = 10150, V1847 = 16335,
+    V1848 = 2040, V1849 = 8225, V1850 = 14410, V1851 = 115, V1852 = 6300, V1853 = 12485, V1854 = 18670, V1855 = 4375,
+    V1856 = 10560, V1857 = 16745, V1858 = 2450, V1859 = 8635, V1860 = 14820, V1861 = 525, V1862 = 6710, V1863 = 12895,
+    V1864 = 19080, V1865 = 4785, V1866 = 10970, V1867 = 17155, V1868 = 2860, V1869 = 9045, V1870 = 15230, V1871 = 935,
+    V1872 = 7120, V1873 = 13305, V1874 = 19490, V1875 = 5195, V1876 = 11380, V1877 = 17565, V1878 = 3270, V1879 = 9455,
+    V1880 = 15640, V1881 = 1345, V1882 = 7530, V1883 = 13715, V1884 = 19900, V1885 = 5605, V1886 = 11790, V1887 = 17975,
+    V1888 = 3680, V1889 = 9865, V1890 = 16050, V1891 = 1755, V1892 = 7940, V1893 = 14125, V1894 = 20310, V1895 = 6015,
+    V1896 = 12200, V1897 = 18385, V1898 = 4090, V1899 = 10275, V1900 = 16460, V1901 = 2165, V1902 = 8350, V1903 = 14535,
+    V1904 = 240, V1905 = 6425, V1906 = 12610, V1907 = 18795, V1908 = 4500, V1909 = 10685, V1910 = 16870, V1911 = 2575,
+    V1912 = 8760, V1913 = 14945, V1914 = 650, V1915 = 6835, V1916 = 13020, V1917 = 19205, V1918 = 4910, V1919 = 11095,
+    V1920 = 17280, V1921 = 2985, V1922 = 9170, V1923 = 15355, V1924 = 1060, V1925 = 7245, V1926 = 13430, V1927 = 19615,
+    V1928 = 5320, V1929 = 11505, V1930 = 17690, V1931 = 3395, V1932 = 9580, V1933 = 15765, V1934 = 1470, V1935 = 7655,
+    V1936 = 13840, V1937 = 20025, V1938 = 5730, V1939 = 11915, V1940 = 18100, V1941 = 3805, V1942 = 9990, V1943 = 16175,
+    V1944 = 1880, V1945 = 8065, V1946 = 14250, V1947 = 20435, V1948 = 6140, V1949 = 12325, V1950 = 18510, V1951 = 4215,
+    V1952 = 10400, V1953 = 16585, V1954 = 2290, V1955 = 8475, V1956 = 14660, V1957 = 365, V1958 = 6550, V1959 = 12735,
+    V1960 = 18920, V1961 = 4625, V1962 = 10810, V1963 = 16995, V1964 = 2700, V1965 = 8885, V1966 = 15070, V1967 = 775,
+    V1968 = 6960, V1969 = 13145, V1970 = 19330, V1971 = 5035, V1972 = 11220, V1973 = 17405, V1974 = 3110, V1975 = 9295,
+    V1976 = 15480, V1977 = 1185, V1978 = 7370, V1979 = 13555, V1980 = 19740, V1981 = 5445, V1982 = 11630, V1983 = 17815,
+    V1984 = 3520, V1985 = 9705, V1986 = 15890, V1987 = 1595, V1988 = 7780, V1989 = 13965, V1990 = 20150, V1991 = 5855,
+    V1992 = 12040, V1993 = 18225, V1994 = 3930, V1995 = 10115, V1996 = 16300, V1997 = 2005, V1998 = 8190, V1999 = 14375,
+    V2000 = 80, V2001 = 6265, V2002 = 12450, V2003 = 18635, V2004 = 4340, V2005 = 10525, V2006 = 16710, V2007 = 2415,
+    V2008 = 8600, V2009 = 14785, V2010 = 490, V2011 = 6675, V2012 = 12860, V2013 = 19045, V2014 = 4750, V2015 = 10935,
+    V2016 = 17120, V2017 = 2825, V2018 = 9010, V2019 = 15195, V2020 = 900, V2021 = 7085, V2022 = 13270, V2023 = 19455,
+    V2024 = 5160, V2025 = 11345, V2026 = 17530, V2027 = 3235, V2028 = 9420, V2029 = 15605, V2030 = 1310, V2031 = 7495,
+    V2032 = 13680, V2033 = 19865, V2034 = 5570, V2035 = 11755, V2036 = 17940, V2037 = 3645, V2038 = 9830, V2039 = 16015,
+    V2040 = 1720, V2041 = 7905, V2042 = 14090, V2043 = 20275, V2044 = 5980, V2045 = 12165, V2046 = 18350, V2047 = 4055,
+    V2048 = 10240, V2049 = 16425, V2050 = 2130, V2051 = 8315, V2052 = 14500, V2053 = 205, V2054 = 6390, V2055 = 12575,
+    V2056 = 18760, V2057 = 4465, V2058 = 10650, V2059 = 16835, V2060 = 2540, V2061 = 8725, V2062 = 14910, V2063 = 615,
+    V2064 = 6800, V2065 = 12985, V2066 = 19170, V2067 = 4875, V2068 = 11060, V2069 = 17245, V2070 = 2950, V2071 = 9135,
+    V2072 = 15320, V2073 = 1025, V2074 = 7210, V2075 = 13395, V2076 = 19580, V2077 = 5285, V2078 = 11470, V2079 = 17655,
+    V2080 = 3360, V2081 = 9545, V2082 = 15730, V2083 = 1435, V2084 = 7620, V2085 = 13805, V2086 = 19990, V2087 = 5695,
+    V2088 = 11880, V2089 = 18065, V2090 = 3770, V2091 = 9955, V2092 = 16140, V2093 = 1845, V2094 = 8030, V2095 = 14215,
+    V2096 = 20400, V2097 = 6105, V2098 = 12290, V2099 = 18475, V2100 = 4180, V2101 = 10365, V2102 = 16550, V2103 = 2255,
+    V2104 = 8440, V2105 = 14625, V2106 = 330, V2107 = 6515, V2108 = 12700, V2109 = 18885, V2110 = 4590, V2111 = 10775,
+    V2112 = 16960, V2113 = 2665, V2114 = 8850, V2115 = 15035, V2116 = 740, V2117 = 6925, V2118 = 13110, V2119 = 19295,
+    V2120 = 5000, V2121 = 11185, V2122 = 17370, V2123 = 3075, V2124 = 9260, V2125 = 15445, V2126 = 1150, V2127 = 7335,
+    V2128 = 13520, V2129 = 19705, V2130 = 5410, V2131 = 11595, V2132 = 17780, V2133 = 3485, V2134 = 9670, V2135 = 15855,
+    V2136 = 1560, V2137 = 7745, V2138 = 13930, V2139 = 20115, V2140 = 5820, V2141 = 12005, V2142 = 18190, V2143 = 3895,
+    V2144 = 10080, V2145 = 16265, V2146 = 1970, V2147 = 8155, V2148 = 14340, V2149 = 45, V2150 = 6230, V2151 = 12415,
+    V2152 = 18600, V2153 = 4305, V2154 = 10490, V2155 = 16675, V2156 = 2380, V2157 = 8565, V2158 = 14750, V2159 = 455,
+    V2160 = 6640, V2161 = 12825, V2162 = 19010, V2163 = 4715, V2164 = 10900, V2165 = 17085, V2166 = 2790, V2167 = 8975,
+    V2168 = 15160, V2169 = 865, V2170 = 7050, V2171 = 13235, V2172 = 19420, V2173 = 5125, V2174 = 11310, V2175 = 17495,
+    V2176 = 3200, V2177 = 9385, V2178 = 15570, V2179 = 1275, V2180 = 7460, V2181 = 13645, V2182 = 19830, V2183 = 5535,
+    V2184 = 11720, V2185 = 17905, V2186 = 3610, V2187 = 9795, V2188 = 15980, V2189 = 1685, V2190 = 7870, V2191 = 14055,
+    V2192 = 20240, V2193 = 5945, V2194 = 12130, V2195 = 18315, V2196 = 4020, V2197 = 10205, V2198 = 16390, V2199 = 2095,
+    V2200 = 8280, V2201 = 14465, V2202 = 170, V2203 = 6355, V2204 = 12540, V2205 = 18725, V2206 = 4430, V2207 = 10615,
+    V2208 = 16800, V2209 = 2505, V2210 = 8690, V2211 = 14875, V2212 = 580, V2213 = 6765, V2214 = 12950, V2215 = 19135,
+    V2216 = 4840, V2217 = 11025, V2218 = 17210, V2219 = 2915, V2220 = 9100, V2221 = 15285, V2222 = 990, V2223 = 7175,
+    V2224 = 13360, V2225 = 19545, V2226 = 5250, V2227 = 11435, V2228 = 17620, V2229 = 3325, V2230 = 9510, V2231 = 15695,
+    V2232 = 1400, V2233 = 7585, V2234 = 13770, V2235 = 19955, V2236 = 5660, V2237 = 11845, V2238 = 18030, V2239 = 3735,
+    V2240 = 9920, V2241 = 16105, V2242 = 1810, V2243 = 7995, V2244 = 14180, V2245 = 20365, V2246 = 6070, V2247 = 12255,
+    V2248 = 18440, V2249 = 4145, V2250 = 10330, V2251 = 16515, V2252 = 2220, V2253 = 8405, V2254 = 14590, V2255 = 295,
+    V2256 = 6480, V2257 = 12665, V2258 = 18850, V2259 = 4555, V2260 = 10740, V2261 = 16925, V2262 = 2630, V2263 = 8815,
+    V2264 = 15000, V2265 = 705, V2266 = 6890, V2267 = 13075, V2268 = 19260, V2269 = 4965, V2270 = 11150, V2271 = 17335,
+    V2272 = 3040, V2273 = 9225, V2274 = 15410, V2275 = 1115, V2276 = 7300, V2277 = 13485, V2278 = 19670, V2279 = 5375,
+    V2280 = 11560, V2281 = 17745, V2282 = 3450, V2283 = 9635, V2284 = 15820, V2285 = 1525, V2286 = 7710, V2287 = 13895,
+    V2288 = 20080, V2289 = 5785, V2290 = 11970, V2291 = 18155, V2292 = 3860, V2293 = 10045, V2294 = 16230, V2295 = 1935,
+    V2296 = 8120, V2297 = 14305, V2298 = 10, V2299 = 6195, V2300 = 12380, V2301 = 18565, V2302 = 4270, V2303 = 10455,
+    V2304 = 16640, V2305 = 2345, V2306 = 8530, V2307 = 14715, V2308 = 420, V2309 = 6605, V2310 = 12790, V2311 = 18975,
+    V2312 = 4680, V2313 = 10865, V2314 = 17050, V2315 = 2755, V2316 = 8940, V2317 = 15125, V2318 = 830, V2319 = 7015,
+    V2320 = 13200, V2321 = 19385, V2322 = 5090, V2323 = 11275, V2324 = 17460, V2325 = 3165, V2326 = 9350, V2327 = 15535,
+    V2328 = 1240, V2329 = 7425, V2330 = 13610, V2331 = 19795, V2332 = 5500, V2333 = 11685, V2334 = 17870, V2335 = 3575,
+    V2336 = 9760, V2337 = 15945, V2338 = 1650, V2339 = 7835, V2340 = 14020, V2341 = 20205, V2342 = 5910, V2343 = 12095,
+    V2344 = 18280, V2345 = 3985, V2346 = 10170, V2347 = 16355, V2348 = 2060, V2349 = 8245, V2350 = 14430, V2351 = 135,
+    V2352 = 6320, V2353 = 12505, V2354 = 18690, V2355 = 4395, V2356 = 10580, V2357 = 16765, V2358 = 2470, V2359 = 8655,
+    V2360 = 14840, V2361 = 545, V2362 = 6730, V2363 = 12915, V2364 = 19100, V2365 = 4805, V2366 = 10990, V2367 = 17175,
+    V2368 = 2880, V2369 = 9065, V2370 = 15250, V2371 = 955, V2372 = 7140, V2373 = 13325, V2374 = 19510, V2375 = 5215,
+    V2376 = 11400, V2377 = 17585, V2378 = 3290, V2379 = 9475, V2380 = 15660, V2381 = 1365, V2382 = 7550, V2383 = 13735,
+    V2384 = 19920, V2385 = 5625, V2386 = 11810, V2387 = 17995, V2388 = 3700, V2389 = 9885, V2390 = 16070, V2391 = 1775,
+    V2392 = 7960, V2393 = 14145, V2394 = 20330, V2395 = 6035, V2396 = 12220, V2397 = 18405, V2398 = 4110, V2399 = 10295,
+    V2400 = 16480, V2401 = 2185, V2402 = 8370, V2403 = 14555, V2404 = 260, V2405 = 6445, V2406 = 12630, V2407 = 18815,
+    V2408 = 4520, V2409 = 10705, V2410 = 16890, V2411 = 2595, V2412 = 8780, V2413 = 14965, V2414 = 670, V2415 = 6855,
+    V2416 = 13040, V2417 = 19225, V2418 = 4930, V2419 = 11115, V2420 = 17300, V2421 = 3005, V2422 = 9190, V2423 = 15375,
+    V2424 = 1080, V2425 = 7265, V2426 = 13450, V2427 = 19635, V2428 = 5340, V2429 = 11525, V2430 = 17710, V2431 = 3415,
+    V2432 = 9600, V2433 = 15785, V2434 = 1490, V2435 = 7675, V2436 = 13860, V2437 = 20045, V2438 = 5750, V2439 = 11935,
+    V2440 = 18120, V2441 = 3825, V2442 = 10010, V2443 = 16195, V2444 = 1900, V2445 = 8085, V2446 = 14270, V2447 = 20455,
+    V2448 = 6160, V2449 = 12345, V2450 = 18530, V2451 = 4235, V2452 = 10420, V2453 = 16605, V2454 = 2310, V2455 = 8495,
+    V2456 = 14680, V2457 = 385, V2458 = 6570, V2459 = 12755, V2460 = 18940, V2461 = 4645, V2462 = 10830, V2463 = 17015,
+    V2464 = 2720, V2465 = 8905, V2466 = 15090, V2467 = 795, V2468 = 6980, V2469 = 13165, V2470 = 19350, V2471 = 5055,
+    V2472 = 11240, V2473 = 17425, V2474 = 3130, V2475 = 9315, V2476 = 15500, V2477 = 1205, V2478 = 7390, V2479 = 13575,
+    V2480 = 19760, V2481 = 5465, V2482 = 11650, V2483 = 17835, V2484 = 3540, V2485 = 9725, V2486 = 15910, V2487 = 1615,
+    V2488 = 7800, V2489 = 13985, V2490 = 20170, V2491 = 5875, V2492 = 12060, V2493 = 18245, V2494 = 3950, V2495 = 10135,
+    V2496 = 16320, V2497 = 2025, V2498 = 8210, V2499 = 14395, V2500 = 100, V2501 = 6285, V2502 = 12470, V2503 = 18655,
+    V2504 = 4360, V2505 = 10545, V2506 = 16730, V2507 = 2435, V2508 = 8620, V2509 = 14805, V2510 = 510, V2511 = 6695,
+    V2512 = 12880, V2513 = 19065, V2514 = 4770, V2515 = 10955, V2516 = 17140, V2517 = 2845, V2518 = 9030, V2519 = 15215,
+    V2520 = 920, V2521 = 7105, V2522 = 13290, V2523 = 19475, V2524 = 5180, V2525 = 11365, V2526 = 17550, V2527 = 3255,
+    V2528 = 9440, V2529 = 15625, V2530 = 1330, V2531 = 7515, V2532 = 13700, V2533 = 19885, V2534 = 5590, V2535 = 11775,
+    V2536 = 17960, V2537 = 3665, V2538 = 9850, V2539 = 16035, V2540 = 1740, V2541 = 7925, V2542 = 14110, V2543 = 20295,
+    V2544 = 6000, V2545 = 12185, V2546 = 18370, V2547 = 4075, V2548 = 10260, V2549 = 16445, V2550 = 2150, V2551 = 8335,
+    V2552 = 14520, V2553 = 225, V2554 = 6410, V2555 = 12595, V2556 = 18780, V2557 = 4485, V2558 = 10670, V2559 = 16855,
+    V2560 = 2560, V2561 = 8745, V2562 = 14930, V2563 = 635, V2564 = 6820, V2565 = 13005, V2566 = 19190, V2567 = 4895,
+    V2568 = 11080, V2569 = 17265, V2570 = 2970, V2571 = 9155, V2572 = 15340, V2573 = 1045, V2574 = 7230, V2575 = 13415,
+    V2576 = 19600, V2577 = 5305, V2578 = 11490, V2579 = 17675, V2580 = 3380, V2581 = 9565, V2582 = 15750, V2583 = 1455,
+    V2584 = 7640, V2585 = 13825, V2586 = 20010, V2587 = 5715, V2588 = 11900, V2589 = 18085, V2590 = 3790, V2591 = 9975,
+    V2592 = 16160, V2593 = 1865, V2594 = 8050, V2595 = 14235, V2596 = 20420, V2597 = 6125, V2598 = 12310, V2599 = 18495,
+    V2600 = 4200, V2601 = 10385, V2602 = 16570, V2603 = 2275, V2604 = 8460, V2605 = 14645, V2606 = 350, V2607 = 6535,
+    V2608 = 12720, V2609 = 18905, V2610 = 4610, V2611 = 10795, V2612 = 16980, V2613 = 2685, V2614 = 8870, V2615 = 15055,
+    V2616 = 760, V2617 = 6945, V2618 = 13130, V2619 = 19315, V2620 = 5020, V2621 = 11205, V2622 = 17390, V2623 = 3095,
+    V2624 = 9280, V2625 = 15465, V2626 = 1170, V2627 = 7355, V2628 = 13540, V2629 = 19725, V2630 = 5430, V2631 = 11615,
+    V2632 = 17800, V2633 = 3505, V2634 = 9690, V2635 = 15875, V2636 = 1580, V2637 = 7765, V2638 = 13950, V2639 = 20135,
+    V2640 = 5840, V2641 = 12025, V2642 = 18210, V2643 = 3915, V2644 = 10100, V2645 = 16285, V2646 = 1990, V2647 = 8175,
+    V2648 = 14360, V2649 = 65, V2650 = 6250, V2651 = 12435, V2652 = 18620, V2653 = 4325, V2654 = 10510, V2655 = 16695,
+    V2656 = 2400, V2657 = 8585, V2658 = 14770, V2659 = 475, V2660 = 6660, V2661 = 12845, V2662 = 19030, V2663 = 4735,
+    V2664 = 10920, V2665 = 17105, V2666 = 2810, V2667 = 8995, V2668 = 15180, V2669 = 885, V2670 = 7070, V2671 = 13255,
+    V2672 = 19440, V2673 = 5145, V2674 = 11330, V2675 = 17515, V2676 = 3220, V2677 = 9405, V2678 = 15590, V2679 = 1295,
+    V2680 = 7480, V2681 = 13665, V2682 = 19850, V2683 = 5555, V2684 = 11740, V2685 = 17925, V2686 = 3630, V2687 = 9815,
+    V2688 = 16000, V2689 = 1705, V2690 = 7890, V2691 = 14075, V2692 = 20260, V2693 = 5965, V2694 = 12150, V2695 = 18335,
+    V2696 = 4040, V2697 = 10225, V2698 = 16410, V2699 = 2115, V2700 = 8300, V2701 = 14485, V2702 = 190, V2703 = 6375,
+    V2704 = 12560, V2705 = 18745, V2706 = 4450, V2707 = 10635, V2708 = 16820, V2709 = 2525, V2710 = 8710, V2711 = 14895,
+    V2712 = 600, V2713 = 6785, V2714 = 12970, V2715 = 19155, V2716 = 4860, V2717 = 11045, V2718 = 17230, V2719 = 2935,
+    V2720 = 9120, V2721 = 15305, V2722 = 1010, V2723 = 7195, V2724 = 13380, V2725 = 19565, V2726 = 5270, V2727 = 11455,
+    V2728 = 17640, V2729 = 3345, V2730 = 9530, V2731 = 15715, V2732 = 1420, V2733 = 7605, V2734 = 13790, V2735 = 19975,
+    V2736 = 5680, V2737 = 11865, V2738 = 18050, V2739 = 3755, V2740 = 9940, V2741 = 16125, V2742 = 1830, V2743 = 8015,
+    V2744 = 14200, V2745 = 20385, V2746 = 6090, V2747 = 12275, V2748 = 18460, V2749 = 4165, V2750 = 10350, V2751 = 16535,
+    V2752 = 2240, V2753 = 8425, V2754 = 14610, V2755 = 315, V2756 = 6500, V2757 = 12685, V2758 = 18870, V2759 = 4575,
+    V2760 = 10760, V2761 = 16945, V2762 = 2650, V2763 = 8835, V2764 = 15020, V2765 = 725, V2766 = 6910, V2767 = 13095,
+    V2768 = 19280, V2769 = 4985, V2770 = 11170, V2771 = 17355, V2772 = 3060, V2773 = 9245, V2774 = 15430, V2775 = 1135,
+    V2776 = 7320, V2777 = 13505, V2778 = 19690, V2779 = 5395, V2780 = 11580, V2781 = 17765, V2782 = 3470, V2783 = 9655,
+    V2784 = 15840, V2785 = 1545, V2786 = 7730, V2787 = 13915, V2788 = 20100, V2789 = 5805, V2790 = 11990, V2791 = 18175,
+    V2792 = 3880, V2793 = 10065, V2794 = 16250, V2795 = 1955, V2796 = 8140, V2797 = 14325, V2798 = 30, V2799 = 6215,
+    V2800 = 12400, V2801 = 18585, V2802 = 4290, V2803 = 10475, V2804 = 16660, V2805 = 2365, V2806 = 8550, V2807 = 14735,
+    V2808 = 440, V2809 = 6625, V2810 = 12810, V2811 = 18995, V2812 = 4700, V2813 = 10885, V2814 = 17070, V2815 = 2775,
+    V2816 = 8960, V2817 = 15145, V2818 = 850, V2819 = 7035, V2820 = 13220, V2821 = 19405, V2822 = 5110, V2823 = 11295,
+    V2824 = 17480, V2825 = 3185, V2826 = 9370, V2827 = 15555, V2828 = 1260, V2829 = 7445, V2830 = 13630, V2831 = 19815,
+    V2832 = 5520, V2833 = 11705, V2834 = 17890, V2835 = 3595, V2836 = 9780, V2837 = 15965, V2838 = 1670, V2839 = 7855,
+    V2840 = 14040, V2841 = 20225, V2842 = 5930, V2843 = 12115, V2844 = 18300, V2845 = 4005, V2846 = 10190, V2847 = 16375,
+    V2848 = 2080, V2849 = 8265, V2850 = 14450, V2851 = 155, V2852 = 6340, V2853 = 12525, V2854 = 18710, V2855 = 4415,
+    V2856 = 10600, V2857 = 16785, V2858 = 2490, V2859 = 8675, V2860 = 14860, V2861 = 565, V2862 = 6750, V2863 = 12935,
+    V2864 = 19120, V2865 = 4825, V2866 = 11010, V2867 = 17195, V2868 = 2900, V2869 = 9085, V2870 = 15270, V2871 = 975,
+    V2872 = 7160, V2873 = 13345, V2874 = 19530, V2875 = 5235, V2876 = 11420, V2877 = 17605, V2878 = 3310, V2879 = 9495,
+    V2880 = 15680, V2881 = 1385, V2882 = 7570, V2883 = 13755, V2884 = 19940, V2885 = 5645, V2886 = 11830, V2887 = 18015,
+    V2888 = 3720, V2889 = 9905, V2890 = 16090, V2891 = 1795, V2892 = 7980, V2893 = 14165, V2894 = 20350, V2895 = 6055,
+    V2896 = 12240, V2897 = 18425, V2898 = 4130, V2899 = 10315, V2900 = 16500, V2901 = 2205, V2902 = 8390, V2903 = 14575,
+    V2904 = 280, V2905 = 6465, V2906 = 12650, V2907 = 18835, V2908 = 4540, V2909 = 10725, V2910 = 16910, V2911 = 2615,
+    V2912 = 8800, V2913 = 14985, V2914 = 690, V2915 = 6875, V2916 = 13060, V2917 = 19245, V2918 = 4950, V2919 = 11135,
+    V2920 = 17320, V2921 = 3025, V2922 = 9210, V2923 = 15395, V2924 = 1100, V2925 = 7285, V2926 = 13470, V2927 = 19655,
+    V2928 = 5360, V2929 = 11545, V2930 = 17730, V2931 = 3435, V2932 = 9620, V2933 = 15805, V2934 = 1510, V2935 = 7695,
+    V2936 = 13880, V2937 = 20065, V2938 = 5770, V2939 = 11955, V2940 = 18140, V2941 = 3845, V2942 = 10030, V2943 = 16215,
+    V2944 = 1920, V2945 = 8105, V2946 = 14290, V2947 = 20475, V2948 = 6180, V2949 = 12365, V2950 = 18550, V2951 = 4255,
+    V2952 = 10440, V2953 = 16625, V2954 = 2330, V2955 = 8515, V2956 = 14700, V2957 = 405, V2958 = 6590, V2959 = 12775,
+    V2960 = 18960, V2961 = 4665, V2962 = 10850, V2963 = 17035, V2964 = 2740, V2965 = 8925, V2966 = 15110, V2967 = 815,
+    V2968 = 7000, V2969 = 13185, V2970 = 19370, V2971 = 5075, V2972 = 11260, V2973 = 17445, V2974 = 3150, V2975 = 9335,
+    V2976 = 15520, V2977 = 1225, V2978 = 7410, V2979 = 13595, V2980 = 19780, V2981 = 5485, V2982 = 11670, V2983 = 17855,
+    V2984 = 3560, V2985 = 9745, V2986 = 15930, V2987 = 1635, V2988 = 7820, V2989 = 14005, V2990 = 20190, V2991 = 5895,
+    V2992 = 12080, V2993 = 18265, V2994 = 3970, V2995 = 10155, V2996 = 16340, V2997 = 2045, V2998 = 8230, V2999 = 14415,
+    V3000 = 120, V3001 = 6305, V3002 = 12490, V3003 = 18675, V3004 = 4380, V3005 = 10565, V3006 = 16750, V3007 = 2455,
+    V3008 = 8640, V3009 = 14825, V3010 = 530, V3011 = 6715, V3012 = 12900, V3013 = 19085, V3014 = 4790, V3015 = 10975,
+    V3016 = 17160, V3017 = 2865, V3018 = 9050, V3019 = 15235, V3020 = 940, V3021 = 7125, V3022 = 13310, V3023 = 19495,
+    V3024 = 5200, V3025 = 11385, V3026 = 17570, V3027 = 3275, V3028 = 9460, V3029 = 15645, V3030 = 1350, V3031 = 7535,
+    V3032 = 13720, V3033 = 19905, V3034 = 5610, V3035 = 11795, V3036 = 17980, V3037 = 3685, V3038 = 9870, V3039 = 16055,
+    V3040 = 1760, V3041 = 7945, V3042 = 14130, V3043 = 20315, V3044 = 6020, V3045 = 12205, V3046 = 18390, V3047 = 4095,
+    V3048 = 10280, V3049 = 16465, V3050 = 2170, V3051 = 8355, V3052 = 14540, V3053 = 245, V3054 = 6430, V3055 = 12615,
+    V3056 = 18800, V3057 = 4505, V3058 = 10690, V3059 = 16875, V3060 = 2580, V3061 = 8765, V3062 = 14950, V3063 = 655,
+    V3064 = 6840, V3065 = 13025, V3066 = 19210, V3067 = 4915, V3068 = 11100, V3069 = 17285, V3070 = 2990, V3071 = 9175,
+    V3072 = 15360, V3073 = 1065, V3074 = 7250, V3075 = 13435, V3076 = 19620, V3077 = 5325, V3078 = 11510, V3079 = 17695,
+    V3080 = 3400, V3081 = 9585, V3082 = 15770, V3083 = 1475, V3084 = 7660, V3085 = 13845, V3086 = 20030, V3087 = 5735,
+    V3088 = 11920, V3089 = 18105, V3090 = 3810, V3091 = 9995, V3092 = 16180, V3093 = 1885, V3094 = 8070, V3095 = 14255,
+    V3096 = 20440, V3097 = 6145, V3098 = 12330, V3099 = 18515, V3100 = 4220, V3101 = 10405, V3102 = 16590, V3103 = 2295,
+    V3104 = 8480, V3105 = 14665, V3106 = 370, V3107 = 6555, V3108 = 12740, V3109 = 18925, V3110 = 4630, V3111 = 10815,
+    V3112 = 17000, V3113 = 2705, V3114 = 8890, V3115 = 15075, V3116 = 780, V3117 = 6965, V3118 = 13150, V3119 = 19335,
+    V3120 = 5040, V3121 = 11225, V3122 = 17410, V3123 = 3115, V3124 = 9300, V3125 = 15485, V3126 = 1190, V3127 = 7375,
+    V3128 = 13560, V3129 = 19745, V3130 = 5450, V3131 = 11635, V3132 = 17820, V3133 = 3525, V3134 = 9710, V3135 = 15895,
+    V3136 = 1600, V3137 = 7785, V3138 = 13970, V3139 = 20155, V3140 = 5860, V3141 = 12045, V3142 = 18230, V3143 = 3935,
+    V3144 = 10120, V3145 = 16305, V3146 = 2010, V3147 = 8195, V3148 = 14380, V3149 = 85, V3150 = 6270, V3151 = 12455,
+    V3152 = 18640, V3153 = 4345, V3154 = 10530, V3155 = 16715, V3156 = 2420, V3157 = 8605, V3158 = 14790, V3159 = 495,
+    V3160 = 6680, V3161 = 12865, V3162 = 19050, V3163 = 4755, V3164 = 10940, V3165 = 17125, V3166 = 2830, V3167 = 9015,
+    V3168 = 15200, V3169 = 905, V3170 = 7090, V3171 = 13275, V3172 = 19460, V3173 = 5165, V3174 = 11350, V3175 = 17535,
+    V3176 = 3240, V3177 = 9425, V3178 = 15610, V3179 = 1315, V3180 = 7500, V3181 = 13685, V3182 = 19870, V3183 = 5575,
+    V3184 = 11760, V3185 = 17945, V3186 = 3650, V3187 = 9835, V3188 = 16020, V3189 = 1725, V3190 = 7910, V3191 = 14095,
+    V3192 = 20280, V3193 = 5985, V3194 = 12170, V3195 = 18355, V3196 = 4060, V3197 = 10245, V3198 = 16430, V3199 = 2135,
+    V3200 = 8320, V3201 = 14505, V3202 = 210, V3203 = 6395, V3204 = 12580, V3205 = 18765, V3206 = 4470, V3207 = 10655,
+    V3208 = 16840, V3209 = 2545, V3210 = 8730, V3211 = 14915, V3212 = 620, V3213 = 6805, V3214 = 12990, V3215 = 19175,
+    V3216 = 4880, V3217 = 11065, V3218 = 17250, V3219 = 2955, V3220 = 9140, V3221 = 15325, V3222 = 1030, V3223 = 7215,
+    V3224 = 13400, V3225 = 19585, V3226 = 5290, V3227 = 11475, V3228 = 17660, V3229 = 3365, V3230 = 9550, V3231 = 15735,
+    V3232 = 1440, V3233 = 7625, V3234 = 13810, V3235 = 19995, V3236 = 5700, V3237 = 11885, V3238 = 18070, V3239 = 3775,
+    V3240 = 9960, V3241 = 16145, V3242 = 1850, V3243 = 8035, V3244 = 14220, V3245 = 20405, V3246 = 6110, V3247 = 12295,
+    V3248 = 18480, V3249 = 4185, V3250 = 10370, V3251 = 16555, V3252 = 2260, V3253 = 8445, V3254 = 14630, V3255 = 335,
+    V3256 = 6520, V3257 = 12705, V3258 = 18890, V3259 = 4595, V3260 = 10780, V3261 = 16965, V3262 = 2670, V3263 = 8855,
+    V3264 = 15040, V3265 = 745, V3266 = 6930, V3267 = 13115, V3268 = 19300, V3269 = 5005, V3270 = 11190, V3271 = 17375,
+    V3272 = 3080, V3273 = 9265, V3274 = 15450, V3275 = 1155, V3276 = 7340, V3277 = 13525, V3278 = 19710, V3279 = 5415,
+    V3280 = 11600, V3281 = 17785, V3282 = 3490, V3283 = 9675, V3284 = 15860, V3285 = 1565, V3286 = 7750, V3287 = 13935,
+    V3288 = 20120, V3289 = 5825, V3290 = 12010, V3291 = 18195, V3292 = 3900, V3293 = 10085, V3294 = 16270, V3295 = 1975,
+    V3296 = 8160, V3297 = 14345, V3298 = 50, V3299 = 6235, V3300 = 12420, V3301 = 18605, V3302 = 4310, V3303 = 10495,
+    V3304 = 16680, V3305 = 2385, V3306 = 8570, V3307 = 14755, V3308 = 460, V3309 = 6645, V3310 = 12830, V3311 = 19015,
+    V3312 = 4720, V3313 = 10905, V3314 = 17090, V3315 = 2795, V3316 = 8980, V3317 = 15165, V3318 = 870, V3319 = 7055,
+    V3320 = 13240, V3321 = 19425, V3322 = 5130, V3323 = 11315, V3324 = 17500, V3325 = 3205, V3326 = 9390, V3327 = 15575,
+    V3328 = 1280, V3329 = 7465, V3330 = 13650, V3331 = 19835, V3332 = 5540, V3333 = 11725, V3334 = 17910, V3335 = 3615,
+    V3336 = 9800, V3337 = 15985, V3338 = 1690, V3339 = 7875, V3340 = 14060, V3341 = 20245, V3342 = 5950, V3343 = 12135,
+    V3344 = 18320, V3345 = 4025, V3346 = 10210, V3347 = 16395, V3348 = 2100, V3349 = 8285, V3350 = 14470, V3351 = 175,
+    V3352 = 6360, V3353 = 12545, V3354 = 18730, V3355 = 4435, V3356 = 10620, V3357 = 16805, V3358 = 2510, V3359 = 8695,
+    V3360 = 14880, V3361 = 585, V3362 = 6770, V3363 = 12955, V3364 = 19140, V3365 = 4845, V3366 = 11030, V3367 = 17215,
+    V3368 = 2920, V3369 = 9105, V3370 = 15290, V3371 = 995, V3372 = 7180, V3373 = 13365, V3374 = 19550, V3375 = 5255,
+    V3376 = 11440, V3377 = 17625, V3378 = 3330, V3379 = 9515, V3380 = 15700, V3381 = 1405, V3382 = 7590, V3383 = 13775,
+    V3384 = 19960, V3385 = 5665, V3386 = 11850, V3387 = 18035, V3388 = 3740, V3389 = 9925, V3390 = 16110, V3391 = 1815,
+    V3392 = 8000, V3393 = 14185, V3394 = 20370, V3395 = 6075, V3396 = 12260, V3397 = 18445, V3398 = 4150, V3399 = 10335,
+    V3400 = 16520, V3401 = 2225, V3402 = 8410, V3403 = 14595, V3404 = 300, V3405 = 6485, V3406 = 12670, V3407 = 18855,
+    V3408 = 4560, V3409 = 10745, V3410 = 16930, V3411 = 2635, V3412 = 8820, V3413 = 15005, V3414 = 710, V3415 = 6895,
+    V3416 = 13080, V3417 = 19265, V3418 = 4970, V3419 = 11155, V3420 = 17340, V3421 = 3045, V3422 = 9230, V3423 = 15415,
+    V3424 = 1120, V3425 = 7305, V3426 = 13490, V3427 = 19675, V3428 = 5380, V3429 = 11565, V3430 = 17750, V3431 = 3455,
+    V3432 = 9640, V3433 = 15825, V3434 = 1530, V3435 = 7715, V3436 = 13900, V3437 = 20085, V3438 = 5790, V3439 = 11975,
+    V3440 = 18160, V3441 = 3865, V3442 = 10050, V3443 = 16235, V3444 = 1940, V3445 = 8125, V3446 = 14310, V3447 = 15,
+    V3448 = 6200, V3449 = 12385, V3450 = 18570, V3451 = 4275, V3452 = 10460, V3453 = 16645, V3454 = 2350, V3455 = 8535,
+    V3456 = 14720, V3457 = 425, V3458 = 6610, V3459 = 12795, V3460 = 18980, V3461 = 4685, V3462 = 10870, V3463 = 17055,
+    V3464 = 2760, V3465 = 8945, V3466 = 15130, V3467 = 835, V3468 = 7020, V3469 = 13205, V3470 = 19390, V3471 = 5095,
+    V3472 = 11280, V3473 = 17465, V3474 = 3170, V3475 = 9355, V3476 = 15540, V3477 = 1245, V3478 = 7430, V3479 = 13615,
+    V3480 = 19800, V3481 = 5505, V3482 = 11690, V3483 = 17875, V3484 = 3580, V3485 = 9765, V3486 = 15950, V3487 = 1655,
+    V3488 = 7840, V3489 = 14025, V3490 = 20210, V3491 = 5915, V3492 = 12100, V3493 = 18285, V3494 = 3990, V3495 = 10175,
+    V3496 = 16360, V3497 = 2065, V3498 = 8250, V3499 = 14435, V3500 = 140, V3501 = 6325, V3502 = 12510, V3503 = 18695,
+    V3504 = 4400, V3505 = 10585, V3506 = 16770, V3507 = 2475, V3508 = 8660, V3509 = 14845, V3510 = 550, V3511 = 6735,
+    V3512 = 12920, V3513 = 19105, V3514 = 4810, V3515 = 10995, V3516 = 17180, V3517 = 2885, V3518 = 9070, V3519 = 15255,
+    V3520 = 960, V3521 = 7145, V3522 = 13330, V3523 = 19515, V3524 = 5220, V3525 = 11405, V3526 = 17590, V3527 = 3295,
+    V3528 = 9480, V3529 = 15665, V3530 = 1370, V3531 = 7555, V3532 = 13740, V3533 = 19925, V3534 = 5630, V3535 = 11815,
+    V3536 = 18000, V3537 = 3705, V3538 = 9890, V3539 = 16075, V3540 = 1780, V3541 = 7965, V3542 = 14150, V3543 = 20335,
+    V3544 = 6040, V3545 = 12225, V3546 = 18410, V3547 = 4115, V3548 = 10300, V3549 = 16485, V3550 = 2190, V3551 = 8375,
+    V3552 = 14560, V3553 = 265, V3554 = 6450, V3555 = 12635, V3556 = 18820, V3557 = 4525, V3558 = 10710, V3559 = 16895,
+    V3560 = 2600, V3561 = 8785, V3562 = 14970, V3563 = 675, V3564 = 6860, V3565 = 13045, V3566 = 19230, V3567 = 4935,
+    V3568 = 11120, V3569 = 17305, V3570 = 3010, V3571 = 9195, V3572 = 15380, V3573 = 1085, V3574 = 7270, V3575 = 13455,
+    V3576 = 19640, V3577 = 5345, V3578 = 11530, V3579 = 17715, V3580 = 3420, V3581 = 9605, V3582 = 15790, V3583 = 1495,
+    V3584 = 7680, V3585 = 13865, V3586 = 20050, V3587 = 5755, V3588 = 11940, V3589 = 18125, V3590 = 3830, V3591 = 10015,
+    V3592 = 16200, V3593 = 1905, V3594 = 8090, V3595 = 14275, V3596 = 20460, V3597 = 6165, V3598 = 12350, V3599 = 18535,
+    V3600 = 4240, V3601 = 10425, V3602 = 16610, V3603 = 2315, V3604 = 8500, V3605 = 14685, V3606 = 390, V3607 = 6575,
+    V3608 = 12760, V3609 = 18945, V3610 = 4650, V3611 = 10835, V3612 = 17020, V3613 = 2725, V3614 = 8910, V3615 = 15095,
+    V3616 = 800, V3617 = 6985, V3618 = 13170, V3619 = 19355, V3620 = 5060, V3621 = 11245, V3622 = 17430, V3623 = 3135,
+    V3624 = 9320, V3625 = 15505, V3626 = 1210, V3627 = 7395, V3628 = 13580, V3629 = 19765, V3630 = 5470, V3631 = 11655,
+    V3632 = 17840, V3633 = 3545, V3634 = 9730, V3635 = 15915, V3636 = 1620, V3637 = 7805, V3638 = 13990, V3639 = 20175,
+    V3640 = 5880, V3641 = 12065, V3642 = 18250, V3643 = 3955, V3644 = 10140, V3645 = 16325, V3646 = 2030, V3647 = 8215,
+    V3648 = 14400, V3649 = 105, V3650 = 6290, V3651 = 12475, V3652 = 18660, V3653 = 4365, V3654 = 10550, V3655 = 16735,
+    V3656 = 2440, V3657 = 8625, V3658 = 14810, V3659 = 515, V3660 = 6700, V3661 = 12885, V3662 = 19070, V3663 = 4775,
+    V3664 = 10960, V3665 = 17145, V3666 = 2850, V3667 = 9035, V3668 = 15220, V3669 = 925, V3670 = 7110, V3671 = 13295,
+    V3672 = 19480, V3673 = 5185, V3674 = 11370, V3675 = 17555, V3676 = 3260, V3677 = 9445, V3678 = 15630, V3679 = 1335,
+    V3680 = 7520, V3681 = 13705, V3682 = 19890, V3683 = 5595, V3684 = 11780, V3685 = 17965, V3686 = 3670, V3687 = 9855,
+    V3688 = 16040, V3689 = 1745, V3690 = 7930, V3691 = 14115, V3692 = 20300, V3693 = 6005, V3694 = 12190, V3695 = 18375,
+    V3696 = 4080, V3697 = 10265, V3698 = 16450, V3699 = 2155, V3700 = 8340, V3701 = 14525, V3702 = 230, V3703 = 6415,
+    V3704 = 12600, V3705 = 18785, V3706 = 4490, V3707 = 10675, V3708 = 16860, V3709 = 2565, V3710 = 8750, V3711 = 14935,
+    V3712 = 640, V3713 = 6825, V3714 = 13010, V3715 = 19195, V3716 = 4900, V3717 = 11085, V3718 = 17270, V3719 = 2975,
+    V3720 = 9160, V3721 = 15345, V3722 = 1050, V3723 = 7235, V3724 = 13420, V3725 = 19605, V3726 = 5310, V3727 = 11495,
+    V3728 = 17680, V3729 = 3385, V3730 = 9570, V3731 = 15755, V3732 = 1460, V3733 = 7645, V3734 = 13830, V3735 = 20015,
+    V3736 = 5720, V3737 = 11905, V3738 = 18090, V3739 = 3795, V3740 = 9980, V3741 = 16165, V3742 = 1870, V3743 = 8055,
+    V3744 = 14240, V3745 = 20425, V3746 = 6130, V3747 = 12315, V3748 = 18500, V3749 = 4205, V3750 = 10390, V3751 = 16575,
+    V3752 = 2280, V3753 = 8465, V3754 = 14650, V3755 = 355, V3756 = 6540, V3757 = 12725, V3758 = 18910, V3759 = 4615,
+    V3760 = 10800, V3761 = 16985, V3762 = 2690, V3763 = 8875, V3764 = 15060, V3765 = 765, V3766 = 6950, V3767 = 13135,
+    V3768 = 19320, V3769 = 5025, V3770 = 11210, V3771 = 17395, V3772 = 3100, V3773 = 9285, V3774 = 15470, V3775 = 1175,
+    V3776 = 7360, V3777 = 13545, V3778 = 19730, V3779 = 5435, V3780 = 11620, V3781 = 17805, V3782 = 3510, V3783 = 9695,
+    V3784 = 15880, V3785 = 1585, V3786 = 7770, V3787 = 13955, V3788 = 20140, V3789 = 5845, V3790 = 12030, V3791 = 18215,
+    V3792 = 3920, V3793 = 10105, V3794 = 16290, V3795 = 1995, V3796 = 8180, V3797 = 14365, V3798 = 70, V3799 = 6255,
+    V3800 = 12440, V3801 = 18625, V3802 = 4330, V3803 = 10515, V3804 = 16700, V3805 = 2405, V3806 = 8590, V3807 = 14775,
+    V3808 = 480, V3809 = 6665, V3810 = 12850, V3811 = 19035, V3812 = 4740, V3813 = 10925, V3814 = 17110, V3815 = 2815,
+    V3816 = 9000, V3817 = 15185, V3818 = 890, V3819 = 7075, V3820 = 13260, V3821 = 19445, V3822 = 5150, V3823 = 11335,
+    V3824 = 17520, V3825 = 3225, V3826 = 9410, V3827 = 15595, V3828 = 1300, V3829 = 7485, V3830 = 13670, V3831 = 19855,
+    V3832 = 5560, V3833 = 11745, V3834 = 17930, V3835 = 3635, V3836 = 9820, V3837 = 16005, V3838 = 1710, V3839 = 7895,
+    V3840 = 14080, V3841 = 20265, V3842 = 5970, V3843 = 12155, V3844 = 18340, V3845 = 4045, V3846 = 10230, V3847 = 16415,
+    V3848 = 2120, V3849 = 8305, V3850 = 14490, V3851 = 195, V3852 = 6380, V3853 = 12565, V3854 = 18750, V3855 = 4455,
+    V3856 = 10640, V3857 = 16825, V3858 = 2530, V3859 = 8715, V3860 = 14900, V3861 = 605, V3862 = 6790, V3863 = 12975,
+    V3864 = 19160, V3865 = 4865, V3866 = 11050, V3867 = 17235, V3868 = 2940, V3869 = 9125, V3870 = 15310, V3871 = 1015,
+    V3872 = 7200, V3873 = 13385, V3874 = 19570, V3875 = 5275, V3876 = 11460, V3877 = 17645, V3878 = 3350, V3879 = 9535,
+    V3880 = 15720, V3881 = 1425, V3882 = 7610, V3883 = 13795, V3884 = 19980, V3885 = 5685, V3886 = 11870, V3887 = 18055,
+    V3888 = 3760, V3889 = 9945, V3890 = 16130, V3891 = 1835, V3892 = 8020, V3893 = 14205, V3894 = 20390, V3895 = 6095,
+    V3896 = 12280, V3897 = 18465, V3898 = 4170, V3899 = 10355, V3900 = 16540, V3901 = 2245, V3902 = 8430, V3903 = 14615,
+    V3904 = 320, V3905 = 6505, V3906 = 12690, V3907 = 18875, V3908 = 4580, V3909 = 10765, V3910 = 16950, V3911 = 2655,
+    V3912 = 8840, V3913 = 15025, V3914 = 730, V3915 = 6915, V3916 = 13100, V3917 = 19285, V3918 = 4990, V3919 = 11175,
+    V3920 = 17360, V3921 = 3065, V3922 = 9250, V3923 = 15435, V3924 = 1140, V3925 = 7325, V3926 = 13510, V3927 = 19695,
+    V3928 = 5400, V3929 = 11585, V3930 = 17770, V3931 = 3475, V3932 = 9660, V3933 = 15845, V3934 = 1550, V3935 = 7735,
+    V3936 = 13920, V3937 = 20105, V3938 = 5810, V3939 = 11995, V3940 = 18180, V3941 = 3885, V3942 = 10070, V3943 = 16255,
+    V3944 = 1960, V3945 = 8145, V3946 = 14330, V3947 = 35, V3948 = 6220, V3949 = 12405, V3950 = 18590, V3951 = 4295,
+    V3952 = 10480, V3953 = 16665, V3954 = 2370, V3955 = 8555, V3956 = 14740, V3957 = 445, V3958 = 6630, V3959 = 12815,
+    V3960 = 19000, V3961 = 4705, V3962 = 10890, V3963 = 17075, V3964 = 2780, V3965 = 8965, V3966 = 15150, V3967 = 855,
+    V3968 = 7040, V3969 = 13225, V3970 = 19410, V3971 = 5115, V3972 = 11300, V3973 = 17485, V3974 = 3190, V3975 = 9375,
+    V3976 = 15560, V3977 = 1265, V3978 = 7450, V3979 = 13635, V3980 = 19820, V3981 = 5525, V3982 = 11710, V3983 = 17895,
+    V3984 = 3600, V3985 = 9785, V3986 = 15970, V3987 = 1675, V3988 = 7860, V3989 = 14045, V3990 = 20230, V3991 = 5935,
+    V3992 = 12120, V3993 = 18305, V3994 = 4010, V3995 = 10195, V3996 = 16380, V3997 = 2085, V3998 = 8270, V3999 = 14455,
+    V4000 = 160, V4001 = 6345, V4002 = 12530, V4003 = 18715, V4004 = 4420, V4005 = 10605, V4006 = 16790, V4007 = 2495,
+    V4008 = 8680, V4009 = 14865, V4010 = 570, V4011 = 6755, V4012 = 12940, V4013 = 19125, V4014 = 4830, V4015 = 11015,
+    V4016 = 17200, V4017 = 2905, V4018 = 9090, V4019 = 15275, V4020 = 980, V4021 = 7165, V4022 = 13350, V4023 = 19535,
+    V4024 = 5240, V4025 = 11425, V4026 = 17610, V4027 = 3315, V4028 = 9500, V4029 = 15685, V4030 = 1390, V4031 = 7575,
+    V4032 = 13760, V4033 = 19945, V4034 = 5650, V4035 = 11835, V4036 = 18020, V4037 = 3725, V4038 = 9910, V4039 = 16095,
+    V4040 = 1800, V4041 = 7985, V4042 = 14170, V4043 = 20355, V4044 = 6060, V4045 = 12245, V4046 = 18430, V4047 = 4135,
+    V4048 = 10320, V4049 = 16505, V4050 = 2210, V4051 = 8395, V4052 = 14580, V4053 = 285, V4054 = 6470, V4055 = 12655,
+    V4056 = 18840, V4057 = 4545, V4058 = 10730, V4059 = 16915, V4060 = 2620, V4061 = 8805, V4062 = 14990, V4063 = 695,
+    V4064 = 6880, V4065 = 13065, V4066 = 19250, V4067 = 4955, V4068 = 11140, V4069 = 17325, V4070 = 3030, V4071 = 9215,
+    V4072 = 15400, V4073 = 1105, V4074 = 7290, V4075 = 13475, V4076 = 19660, V4077 = 5365, V4078 = 11550, V4079 = 17735,
+    V4080 = 3440, V4081 = 9625, V4082 = 15810, V4083 = 1515, V4084 = 7700, V4085 = 13885, V4086 = 20070, V4087 = 5775,
+    V4088 = 11960, V4089 = 18145, V4090 = 3850, V4091 = 10035, V4092 = 16220, V4093 = 1925, V4094 = 8110, V4095 = 14295,
+);
+
+#[test]
+fn sparse() {
+    assert_eq!(Sparse::COUNT, 4096);
+    for (i, variant) in Sparse::VARIANTS.iter().enumerate() {
+        assert_eq!(*variant as usize, i * 5);
+        assert_eq!(variant.variant_index(), i);
+    }
+
+    const TABLE: EnumTable<Sparse, u16, { Sparse::COUNT }> =
+        enum_table::et!(Sparse, u16, |variant| *variant as u16);
+    const SECOND: u16 = *TABLE.get_const(&Sparse::V0001);
+    assert_eq!(SECOND, 1237 * 5);
+    assert_eq!(TABLE.get(&Sparse::V4095), &14295);
+}
+
+#[test]
+fn declared() {
+    assert_eq!(Declared::VARIANTS[1], Declared::V0001);
+    for (i, variant) in Declared::VARIANTS.iter().enumerate() {
+        assert_eq!(variant.variant_index(), i);
+    }
+
+    const TABLE: EnumTable<Declared, u16, { Declared::COUNT }> =
+        enum_table::et!(Declared, u16, |variant| *variant as u16);
+    const FIRST: u16 = *TABLE.get_const(&Declared::V0001);
+    assert_eq!(FIRST, 1237 * 5);
+    assert_eq!(TABLE.as_slice()[4095], 14295);
+}
+
+#[test]
+fn dense() {
+    for (i, variant) in Dense::VARIANTS.iter().enumerate() {
+        assert_eq!(variant.variant_index(), i);
+    }
+
+    const TABLE: EnumTable<Dense, usize, { Dense::COUNT }> =
+        enum_table::et!(Dense, usize, |variant| *variant as usize);
+    const LAST: usize = *TABLE.get_const(&Dense::V4095);
+    assert_eq!(LAST, 4095);
+}
+//
@@ -31,3 +31,757 @@ fn test() {
 
     assert_eq!(table.get(&Test::A), &"AAA");
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Action {
+    Move(Direction),
+    Turn { to: Direction },
+    Wait,
+}
+
+#[test]
+fn data_carrying_variants() {
+    assert_eq!(Action::COUNT, 9);
+    assert_eq!(Action::VARIANTS[0], Action::Move(Direction::North));
+    assert_eq!(
+        Action::VARIANTS[4],
+        Action::Turn {
+            to: Direction::North
+        }
+    );
+    assert_eq!(Action::VARIANTS[8], Action::Wait);
+
+    for (i, action) in Action::VARIANTS.iter().enumerate() {
+        assert_eq!(action.variant_index(), i);
+    }
+
+    const TABLE: EnumTable<Action, u8, { Action::COUNT }> =
+        enum_table::et!(Action, u8, |action| match action {
+            Action::Move(_) => 1,
+            Action::Turn { .. } => 2,
+            Action::Wait => 3,
+        });
+    const TURN: &u8 = TABLE.get_const(&Action::Turn {
+        to: Direction::West,
+    });
+    assert_eq!(*TURN, 2);
+
+    let mut table = TABLE;
+    table.set(&Action::Move(Direction::South), 10);
+    assert_eq!(table.get(&Action::Move(Direction::South)), &10);
+    assert_eq!(table.get(&Action::Move(Direction::North)), &1);
+    assert_eq!(table.get(&Action::Wait), &3);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Team {
+    Red,
+    Blue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Role {
+    Attack,
+    Defend,
+    Support,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub struct Player {
+    team: Team,
+    role: Role,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub struct Heading(Team, Direction, Role);
+
+#[test]
+fn struct_keys() {
+    assert_eq!(Player::COUNT, 6);
+    assert_eq!(
+        Player::VARIANTS[..4],
+        [
+            Player {
+                team: Team::Red,
+                role: Role::Attack
+            },
+            Player {
+                team: Team::Red,
+                role: Role::Defend
+            },
+            Player {
+                team: Team::Red,
+                role: Role::Support
+            },
+            Player {
+                team: Team::Blue,
+                role: Role::Attack
+            },
+        ]
+    );
+    for (i, player) in Player::VARIANTS.iter().enumerate() {
+        assert_eq!(player.variant_index(), i);
+    }
+
+    const TABLE: EnumTable<Player, u8, { Player::COUNT }> =
+        enum_table::et!(Player, u8, |player| player.team as u8 * 10
+            + player.role as u8);
+    const BLUE_SUPPORT: &u8 = TABLE.get_const(&Player {
+        team: Team::Blue,
+        role: Role::Support,
+    });
+    assert_eq!(*BLUE_SUPPORT, 12);
+
+    let mut table = TABLE;
+    table.set(
+        &Player {
+            team: Team::Red,
+            role: Role::Defend,
+        },
+        99,
+    );
+    assert_eq!(
+        table.get(&Player {
+            team: Team::Red,
+            role: Role::Defend
+        }),
+        &99
+    );
+    assert_eq!(
+        table.get(&Player {
+            team: Team::Blue,
+            role: Role::Defend
+        }),
+        &11
+    );
+
+    assert_eq!(Heading::COUNT, 24);
+    let heading = Heading(Team::Blue, Direction::South, Role::Defend);
+    assert_eq!(heading.variant_index(), 12 + 2 * 3 + 1);
+    assert_eq!(Heading::VARIANTS[heading.variant_index()], heading);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Seating {
+    Pair(Player),
+    Nothing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Marker {
+    Pair(Player),
+    Facing(Direction),
+}
+
+#[test]
+fn struct_payloads() {
+    const SEATING: EnumTable<Seating, u8, { Seating::COUNT }> =
+        enum_table::et!(Seating, u8, |seating| match seating {
+            Seating::Pair(player) => player.team as u8 * 10 + player.role as u8,
+            Seating::Nothing => 99,
+        });
+    const NOTHING: u8 = *SEATING.get_const(&Seating::Nothing);
+    assert_eq!(NOTHING, 99);
+
+    let mut table = SEATING;
+    for (i, seating) in Seating::VARIANTS.iter().enumerate() {
+        assert_eq!(seating.variant_index(), i);
+        assert_eq!(table.get_const(seating), table.get(seating));
+        table.set_const(seating, i as u8);
+        assert_eq!(table.get(seating), &(i as u8));
+    }
+
+    let mut table =
+        EnumTable::<Marker, usize, { Marker::COUNT }>::new_with_fn(|marker| marker.variant_index());
+    assert_eq!(Marker::COUNT, Player::COUNT + Direction::COUNT);
+    for (i, marker) in Marker::VARIANTS.iter().enumerate() {
+        assert_eq!(table.get_const(marker), &i);
+        *table.get_mut_const(marker) += 1;
+    }
+    let south = Marker::Facing(Direction::South);
+    assert_eq!(table.get(&south), &(south.variant_index() + 1));
+
+    let set = enum_table::EnumSet::<Seating>::new().with(Seating::Nothing);
+    assert!(set.contains_const(&Seating::Nothing));
+    assert!(!set.contains_const(&Seating::VARIANTS[0]));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Single {
+    Only,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Empty {}
+
+#[test]
+fn single_variant_enum() {
+    assert_eq!(core::mem::size_of::<Single>(), 0);
+    assert_eq!(Single::VARIANTS, &[Single::Only]);
+    assert_eq!(Single::COUNT, 1);
+    assert_eq!(Single::Only.variant_index(), 0);
+
+    const TABLE: EnumTable<Single, u8, { Single::COUNT }> =
+        enum_table::et!(Single, u8, |_variant| 7);
+    const ONLY: &u8 = TABLE.get_const(&Single::Only);
+    assert_eq!(*ONLY, 7);
+
+    let mut table = TABLE;
+    table.set(&Single::Only, 8);
+    assert_eq!(table.get(&Single::Only), &8);
+    assert_eq!(table.iter().collect::<Vec<_>>(), vec![(&Single::Only, &8)]);
+}
+
+#[test]
+fn empty_enum() {
+    assert_eq!(Empty::VARIANTS, &[]);
+    assert_eq!(Empty::COUNT, 0);
+
+    const TABLE: EnumTable<Empty, u8, { Empty::COUNT }> = enum_table::et!(Empty, u8, |_variant| 0);
+    assert_eq!(TABLE.len(), 0);
+    assert!(TABLE.is_empty());
+    assert_eq!(TABLE.iter().count(), 0);
+    assert_eq!(TABLE.into_iter().count(), 0);
+}
+
+macro_rules! signed_repr_test {
+    ($name:ident, $repr:ident, $min:expr, $max:expr) => {
+        #[test]
+        fn $name() {
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+            #[repr($repr)]
+            enum Temp {
+                Hot = $max,
+                Cold = -1,
+                Mild = 0,
+                Freezing = $min,
+                Warm = 1,
+            }
+
+            assert_eq!(
+                Temp::VARIANTS,
+                &[
+                    Temp::Freezing,
+                    Temp::Cold,
+                    Temp::Mild,
+                    Temp::Warm,
+                    Temp::Hot
+                ]
+            );
+            for (i, temp) in Temp::VARIANTS.iter().enumerate() {
+                assert_eq!(temp.variant_index(), i);
+            }
+
+            const TABLE: EnumTable<Temp, $repr, { Temp::COUNT }> =
+                enum_table::et!(Temp, $repr, |temp| *temp as $repr);
+            const COLD: &$repr = TABLE.get_const(&Temp::Cold);
+            const FREEZING: &$repr = TABLE.get_const(&Temp::Freezing);
+            assert_eq!(*COLD, -1);
+            assert_eq!(*FREEZING, $min);
+            assert_eq!(
+                TABLE.values().copied().collect::<Vec<_>>(),
+                vec![$min, -1, 0, 1, $max]
+            );
+        }
+    };
+}
+
+signed_repr_test!(signed_repr_i8, i8, i8::MIN, i8::MAX);
+signed_repr_test!(signed_repr_i16, i16, i16::MIN, i16::MAX);
+signed_repr_test!(signed_repr_i32, i32, i32::MIN, i32::MAX);
+signed_repr_test!(signed_repr_i64, i64, i64::MIN, i64::MAX);
+signed_repr_test!(signed_repr_i128, i128, i128::MIN, i128::MAX);
+
+#[test]
+fn negative_discriminants_without_repr() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    enum Level {
+        High = 100,
+        Low = -100,
+        Zero = 0,
+    }
+
+    assert_eq!(Level::VARIANTS, &[Level::Low, Level::Zero, Level::High]);
+    assert_eq!(Level::Zero.variant_index(), 1);
+}
+
+#[test]
+fn negative_discriminant_expressions_without_repr() {
+    const STEP: isize = 7;
+    const BELOW: isize = -300;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    enum Temperature {
+        #[enumable(group = "cold")]
+        Freezing = BELOW,
+        Mild = STEP,
+        #[enumable(group = "cold")]
+        Chilly = 0 - STEP,
+        Hot = 2 * STEP,
+        #[enumable(group = "cold")]
+        Cool = STEP - 8,
+    }
+
+    assert_eq!(
+        Temperature::VARIANTS,
+        &[
+            Temperature::Freezing,
+            Temperature::Chilly,
+            Temperature::Cool,
+            Temperature::Mild,
+            Temperature::Hot
+        ]
+    );
+    assert_eq!(
+        Temperature::COLD,
+        &[
+            Temperature::Freezing,
+            Temperature::Chilly,
+            Temperature::Cool
+        ]
+    );
+    for (i, temperature) in Temperature::VARIANTS.iter().enumerate() {
+        assert_eq!(temperature.variant_index(), i);
+    }
+
+    const TABLE: EnumTable<Temperature, isize, { Temperature::COUNT }> =
+        enum_table::et!(Temperature, isize, |temperature| *temperature as isize);
+    assert_eq!(*TABLE.get_const(&Temperature::Chilly), -7);
+    assert_eq!(TABLE.as_slice(), &[-300, -7, -1, 7, 14]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+#[enumable(order = "declaration")]
+#[repr(u8)]
+pub enum Column {
+    Name = 30,
+    Size = 10,
+    Modified = 20,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+#[enumable(order = "declaration")]
+#[repr(i8)]
+pub enum Signal {
+    Up = 1,
+    Down = -1,
+    Idle = 0,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+pub enum Sort {
+    By(Column),
+    Unsorted,
+}
+
+#[test]
+fn declaration_order() {
+    assert_eq!(
+        Column::VARIANTS,
+        &[Column::Name, Column::Size, Column::Modified]
+    );
+    assert_eq!(Column::Size.variant_index(), 1);
+
+    const TABLE: EnumTable<Column, &str, { Column::COUNT }> =
+        enum_table::et!(Column, &str, |column| match column {
+            Column::Name => "name",
+            Column::Size => "size",
+            Column::Modified => "modified",
+        });
+    const MODIFIED: &&str = TABLE.get_const(&Column::Modified);
+    assert_eq!(*MODIFIED, "modified");
+    assert_eq!(TABLE.as_slice(), &["name", "size", "modified"]);
+    assert_eq!(
+        TABLE.keys().copied().collect::<Vec<_>>(),
+        vec![Column::Name, Column::Size, Column::Modified]
+    );
+
+    let mut table = TABLE;
+    table.set(&Column::Size, "bytes");
+    assert_eq!(table.get(&Column::Size), &"bytes");
+
+    assert_eq!(Signal::VARIANTS, &[Signal::Up, Signal::Down, Signal::Idle]);
+    const SIGNALS: EnumTable<Signal, i8, { Signal::COUNT }> =
+        enum_table::et!(Signal, i8, |signal| *signal as i8);
+    const DOWN: &i8 = SIGNALS.get_const(&Signal::Down);
+    assert_eq!(*DOWN, -1);
+
+    assert_eq!(Sort::VARIANTS[1], Sort::By(Column::Size));
+    const SORTS: EnumTable<Sort, u8, { Sort::COUNT }> =
+        enum_table::et!(Sort, u8, |sort| match sort {
+            Sort::By(column) => *column as u8,
+            Sort::Unsorted => 0,
+        });
+    const BY_MODIFIED: &u8 = SORTS.get_const(&Sort::By(Column::Modified));
+    assert_eq!(*BY_MODIFIED, 20);
+}
+
+#[test]
+fn discriminants() {
+    use enum_table::EnumableRepr;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[repr(u16)]
+    enum Contiguous {
+        C = 12,
+        A = 10,
+        B = 11,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(order = "declaration")]
+    #[repr(i32)]
+    enum Sparse {
+        Low = -70000,
+        Mid = 0,
+        High = 70000,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[repr(i8)]
+    enum Extremes {
+        Min = i8::MIN,
+        Max = i8::MAX,
+    }
+
+    for raw in 0..20 {
+        let variant = Contiguous::from_discriminant(raw);
+        assert_eq!(variant.is_some(), (10..=12).contains(&raw));
+        if let Some(variant) = variant {
+            assert_eq!(variant.discriminant(), raw);
+        }
+    }
+
+    assert_eq!(Sparse::from_discriminant(-70000), Some(Sparse::Low));
+    assert_eq!(Sparse::from_discriminant(70000), Some(Sparse::High));
+    assert_eq!(Sparse::from_discriminant(1), None);
+    assert_eq!(Sparse::Mid.discriminant(), 0);
+
+    assert_eq!(Extremes::from_discriminant(i8::MIN), Some(Extremes::Min));
+    assert_eq!(Extremes::from_discriminant(0), None);
+    assert_eq!(Test::from_discriminant(20), Some(Test::C));
+    assert_eq!(Test::from_discriminant(21), None);
+
+    let table = EnumTable::<Sparse, &str, { Sparse::COUNT }>::new_with_fn(|sparse| match sparse {
+        Sparse::Low => "low",
+        Sparse::Mid => "mid",
+        Sparse::High => "high",
+    });
+    assert_eq!(table.get_by_discriminant(70000), Some(&"high"));
+    assert_eq!(table.get_by_discriminant(-1), None);
+}
+
+#[test]
+fn const_lookups() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[repr(u32)]
+    enum Far {
+        Near = 0,
+        Away = 1_000_000,
+    }
+
+    // `Test` (1, 20, 100) gets a direct lookup table; `Far` is too spread out for one.
+    const TABLE: EnumTable<Test, u8, { Test::COUNT }> =
+        enum_table::et!(Test, u8, |variant| *variant as u8);
+    const A: u8 = *TABLE.get_const(&Test::A);
+    const B: u8 = *TABLE.get_const(&Test::B);
+    assert_eq!((A, B), (100, 1));
+
+    const FAR: EnumTable<Far, &str, { Far::COUNT }> =
+        enum_table::et!(Far, &'static str, |far| match far {
+            Far::Near => "near",
+            Far::Away => "away",
+        });
+    const AWAY: &str = FAR.get_const(&Far::Away);
+    assert_eq!(AWAY, "away");
+    assert_eq!(FAR.get_const(&Far::Near), &"near");
+}
+
+#[test]
+fn names() {
+    use enum_table::EnumableNames;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(rename_all = "snake_case")]
+    #[repr(u8)]
+    enum Setting {
+        /// How loud the output is.
+        ///
+        /// Ranges from 0 to 10.
+        OutputVolume = 3,
+        #[enumable(rename = "theme")]
+        ColorTheme = 1,
+        /// Whether to check for updates.
+        AutoUpdate = 2,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(order = "declaration", rename_all = "SCREAMING-KEBAB-CASE")]
+    enum Declared {
+        LastOne = 2,
+        FirstOne = 1,
+    }
+
+    // Names and docs line up with `VARIANTS`, which are sorted by discriminant.
+    assert_eq!(
+        Setting::VARIANTS,
+        &[
+            Setting::ColorTheme,
+            Setting::AutoUpdate,
+            Setting::OutputVolume
+        ]
+    );
+    assert_eq!(Setting::NAMES, &["theme", "auto_update", "output_volume"]);
+    assert_eq!(
+        Setting::DOCS,
+        &[
+            "",
+            "Whether to check for updates.",
+            "How loud the output is.\n\nRanges from 0 to 10."
+        ]
+    );
+    for variant in Setting::VARIANTS {
+        assert_eq!(variant.name(), Setting::NAMES[variant.variant_index()]);
+    }
+
+    assert_eq!(Declared::NAMES, &["LAST-ONE", "FIRST-ONE"]);
+    assert_eq!(Declared::FirstOne.name(), "FIRST-ONE");
+    assert_eq!(Test::NAMES, &["B", "C", "A"]);
+}
+
+#[test]
+fn props() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[repr(u8)]
+    enum Color {
+        #[enumable(prop(weight = 3, label = "Red", hex = 0xff0000u32))]
+        Red = 2,
+        #[enumable(prop(weight = -1, label = "Green", hex = 0x00ff00))]
+        Green = 0,
+        #[enumable(prop(label = "Blue", hex = 0x0000ff, weight = 7))]
+        Blue = 1,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(order = "declaration")]
+    enum Planet {
+        #[enumable(prop(gravity = 3.7, habitable = false, symbol = '☿', r#type = "rocky"))]
+        Mercury = 2,
+        #[enumable(prop(gravity = 9.8, habitable = true, symbol = '♁', r#type = "rocky"))]
+        Earth = 1,
+    }
+
+    const RED_WEIGHT: i32 = *Color::WEIGHT_TABLE.get_const(&Color::Red);
+    assert_eq!(RED_WEIGHT, 3);
+    assert_eq!(Color::WEIGHT_TABLE.as_slice(), &[-1, 7, 3]);
+    assert_eq!(Color::LABEL_TABLE.get(&Color::Blue), &"Blue");
+    let hex: &EnumTable<Color, u32, 3> = &Color::HEX_TABLE;
+    assert_eq!(hex.get(&Color::Green), &0x00ff00);
+
+    assert_eq!(Planet::GRAVITY_TABLE.as_slice(), &[3.7, 9.8]);
+    assert_eq!(Planet::HABITABLE_TABLE.get(&Planet::Earth), &true);
+    assert_eq!(Planet::SYMBOL_TABLE.get(&Planet::Mercury), &'☿');
+    assert_eq!(Planet::TYPE_TABLE.get(&Planet::Earth), &"rocky");
+}
+
+#[test]
+fn enum_kind() {
+    use enum_table::EnumKind;
+
+    #[derive(Debug, EnumKind)]
+    #[allow(dead_code)]
+    enum Message<'a, T> {
+        /// Some text.
+        Text(&'a str),
+        Move {
+            x: T,
+            y: T,
+        },
+        Quit,
+    }
+
+    #[derive(EnumKind)]
+    #[enum_kind(name = "Op")]
+    #[allow(dead_code)]
+    enum Instruction {
+        Push(u8),
+        Pop,
+    }
+
+    assert_eq!(
+        MessageKind::VARIANTS,
+        &[MessageKind::Text, MessageKind::Move, MessageKind::Quit]
+    );
+    assert_eq!(Message::<i32>::Quit.kind(), MessageKind::Quit);
+    assert_eq!(
+        EnumKind::kind(&Message::Move { x: 1, y: 2 }),
+        MessageKind::Move
+    );
+    assert_eq!(Instruction::Push(1).kind(), Op::Push);
+
+    let mut handled = EnumTable::<MessageKind, u32, { MessageKind::COUNT }>::new_fill_with_copy(0);
+    for message in [Message::<u8>::Text("a"), Message::Quit, Message::Text("b")] {
+        *handled.get_mut_for(&message) += 1;
+    }
+    assert_eq!(handled.get_for(&Message::<u8>::Text("")), &2);
+    assert_eq!(handled.as_slice(), &[2, 0, 1]);
+}
+
+#[test]
+fn int_newtypes() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(range = 0..16)]
+    struct Channel(u8);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(range = -3..=3)]
+    struct Offset {
+        steps: i16,
+    }
+
+    assert_eq!(Channel::COUNT, 16);
+    assert_eq!(Channel::VARIANTS[15], Channel(15));
+    assert_eq!(Channel(9).variant_index(), 9);
+    assert_eq!(Channel::new(15), Some(Channel(15)));
+    assert_eq!(Channel::new(16), None);
+
+    assert_eq!(Offset::COUNT, 7);
+    assert_eq!(Offset::VARIANTS[0], Offset { steps: -3 });
+    assert_eq!(Offset { steps: 2 }.variant_index(), 5);
+    assert_eq!(Offset::new(-4), None);
+
+    let mut volumes = EnumTable::<Channel, u8, { Channel::COUNT }>::new_fill_with_copy(100);
+    volumes.set(&Channel(3), 40);
+    assert_eq!(volumes.get(&Channel(3)), &40);
+    assert_eq!(
+        volumes.iter().filter(|(_, volume)| **volume == 100).count(),
+        15
+    );
+
+    const OFFSETS: EnumTable<Offset, i32, { Offset::COUNT }> =
+        enum_table::et!(Offset, i32, |offset| offset.steps as i32 * 10);
+    const UP: i32 = *OFFSETS.get_const(&Offset { steps: 1 });
+    assert_eq!(UP, 10);
+    assert_eq!(OFFSETS.get(&Offset { steps: -3 }), &-30);
+}
+
+#[test]
+#[should_panic(expected = "16 is outside the range 0..=15 of `Channel`")]
+fn int_newtype_out_of_range() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(range = 0..16)]
+    struct Channel(u8);
+
+    Channel(16).variant_index();
+}
+
+#[test]
+fn int_newtypes_of_whole_types() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(range = 0..256)]
+    struct Byte(u8);
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    #[enumable(range = -128..=127)]
+    struct Sample {
+        level: i8,
+    }
+
+    assert_eq!(Byte::COUNT, 256);
+    assert_eq!(Byte(255).variant_index(), 255);
+    assert_eq!(Byte::new(7), Some(Byte(7)));
+    assert_eq!(Sample::COUNT, 256);
+    assert_eq!(Sample { level: -128 }.variant_index(), 0);
+    assert_eq!(Sample::new(127), Some(Sample { level: 127 }));
+}
+
+#[test]
+fn generic_tables() {
+    use enum_table::{AnyEnumTable, EnumTableOf, EnumableTable};
+
+    fn tally<K: EnumableTable>(keys: impl IntoIterator<Item = K>) -> EnumTableOf<K, u32> {
+        let mut counts = EnumTableOf::<K, u32>::new_with_fn(|_| 0);
+        for key in keys {
+            counts[key] += 1;
+        }
+        counts
+    }
+
+    fn most_common<K: EnumableTable>(counts: &EnumTableOf<K, u32>) -> Option<K> {
+        counts
+            .iter()
+            .max_by_key(|(_, count)| **count)
+            .map(|(key, _)| *key)
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Enumable)]
+    struct Pair {
+        test: Test,
+        flag: bool,
+    }
+
+    let counts = tally([Test::A, Test::C, Test::A]);
+    let table: &EnumTable<Test, u32, { Test::COUNT }> = &counts;
+    assert_eq!(table.as_slice(), &[0, 1, 2]);
+    assert_eq!(most_common::<Test>(&counts), Some(Test::A));
+
+    let pairs = tally([Pair {
+        test: Test::B,
+        flag: true,
+    }]);
+    assert_eq!(pairs.values().sum::<u32>(), 1);
+    assert_eq!(tally([true, false, false]).as_slice(), &[2, 1]);
+    assert_eq!(
+        tally([core::cmp::Ordering::Less]).into_iter().next(),
+        Some((core::cmp::Ordering::Less, 1))
+    );
+
+    let tosses = tally([Some(Test::C), None, Some(Test::C)]);
+    assert_eq!(tosses.as_slice(), &[1, 0, 2, 0]);
+    assert_eq!(most_common::<Option<Test>>(&tosses), Some(Some(Test::C)));
+    let results = tally([Ok(true), Err(Test::A), Ok(true)]);
+    assert_eq!(results.as_slice(), &[0, 2, 0, 0, 1]);
+    let triples = tally([(Test::A, false, Some(true))]);
+    assert_eq!(triples[(Test::A, false, Some(true))], 1);
+    assert_eq!(triples.as_slice().len(), 18);
+}
+
+#[test]
+fn composite_collections() {
+    use enum_table::{AnyEnumTable, EnumCounter, EnumMap, EnumMatrix, EnumSet};
+
+    let set: EnumSet<Option<Test>> = [None, Some(Test::A)].into_iter().collect();
+    assert_eq!(
+        set.complement().iter().collect::<Vec<_>>(),
+        [Some(Test::B), Some(Test::C)]
+    );
+
+    let mut map = EnumMap::<(Test, bool), &str, { <(Test, bool)>::COUNT }>::new();
+    map.insert((Test::C, true), "c");
+    map.insert((Test::B, false), "b");
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        [(Test::B, false), (Test::C, true)]
+    );
+    assert_eq!(map.get(&(Test::C, true)), Some(&"c"));
+
+    let counter: EnumCounter<Result<Test, bool>> =
+        [Ok(Test::A), Err(false), Ok(Test::A)].into_iter().collect();
+    assert_eq!(counter.most_common(1), [(Ok(Test::A), 2)]);
+    assert_eq!(counter.total(), 3);
+
+    let matrix = EnumMatrix::<Option<bool>, (bool, bool), usize>::new_with_fn(|row, column| {
+        row.variant_index() * 10 + column.variant_index()
+    });
+    assert_eq!(matrix.get(&Some(true), &(true, false)), &22);
+    assert_eq!(matrix.transpose().get(&(false, true), &None), &1);
+    assert_eq!(matrix.row(&None).as_slice(), &[0, 1, 2, 3]);
+}